@@ -0,0 +1,52 @@
+use iced::{Element, Font, Task};
+use page_poker::{FIRA_MONO_BYTES, Fetcher, Fixtures, PagePoker, StyleConfig, UrlAnalyzer};
+
+use crate::cli::Args;
+
+const FIRA_MONO: Font = Font::with_name("Fira Mono");
+
+struct App {
+    poker: PagePoker,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Poker(page_poker::Message),
+}
+
+/// Open the app window, starting out with the options in `args`.
+pub fn run(args: &Args) -> iced::Result {
+    match &args.offline {
+        Some(dir) => open(Fixtures::dir(dir), args.analyzer()),
+        None => open(args.network(), args.analyzer()),
+    }
+}
+
+fn open(fetcher: impl Fetcher + Clone + 'static, model: UrlAnalyzer) -> iced::Result {
+    let boot = move || {
+        let mut poker = PagePoker::with_fetcher(StyleConfig::default(), fetcher.clone());
+        poker.restore(model.clone());
+        App { poker }
+    };
+    iced::application(boot, App::update, App::view)
+        .title("Page Poker")
+        .font(FIRA_MONO_BYTES)
+        .default_font(FIRA_MONO)
+        .run()
+}
+
+impl App {
+    fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::Poker(msg) => self.poker.update(msg).map(Message::Poker),
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        iced::widget::container(self.poker.view().map(Message::Poker))
+            .padding(30)
+            .width(iced::Fill)
+            .height(iced::Fill)
+            .into()
+    }
+}
@@ -0,0 +1,419 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+use reqwest::header::{self, HeaderMap, HeaderValue};
+use serde::{Deserialize, Serialize};
+
+use crate::fetch::{self, Hop};
+use crate::{FetchOutcome, Metadata, Timing};
+
+/// Responses kept in memory before the oldest ones are dropped.
+pub const CAPACITY: usize = 100;
+
+/// Where a response came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum CacheStatus {
+    /// Downloaded in full.
+    #[default]
+    Fetched,
+    /// Still fresh, so served without asking the server.
+    Hit,
+    /// The server answered `304 Not Modified` to a conditional request.
+    Revalidated,
+}
+
+impl fmt::Display for CacheStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CacheStatus::Fetched => write!(f, "fresh fetch"),
+            CacheStatus::Hit => write!(f, "cache hit"),
+            CacheStatus::Revalidated => write!(f, "revalidated (304)"),
+        }
+    }
+}
+
+/// A stored response and its validators.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Cached {
+    /// Checked on reading from disk, where files are named by a hash of it.
+    url: String,
+    status: u16,
+    headers: Vec<(String, String)>,
+    /// Kept next to the JSON in its own file when on disk.
+    #[serde(skip)]
+    body: Vec<u8>,
+    stored: SystemTime,
+}
+
+impl Cached {
+    fn header(&self, name: &str) -> Option<&str> {
+        fetch::header(&self.headers, name)
+    }
+
+    /// `max-age` hasn't run out and the server didn't ask to always check.
+    pub(crate) fn is_fresh(&self) -> bool {
+        let control = CacheControl::parse(self.header("cache-control"));
+        let age = self.stored.elapsed().unwrap_or(Duration::MAX);
+        !control.no_cache && control.max_age.is_some_and(|max_age| age < max_age)
+    }
+
+    /// `If-None-Match` and `If-Modified-Since` from the stored validators.
+    pub(crate) fn conditional_headers(&self) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        let validators = [
+            ("etag", header::IF_NONE_MATCH),
+            ("last-modified", header::IF_MODIFIED_SINCE),
+        ];
+        for (validator, name) in validators {
+            if let Some(value) = self
+                .header(validator)
+                .and_then(|value| HeaderValue::from_str(value).ok())
+            {
+                headers.insert(name, value);
+            }
+        }
+        headers
+    }
+
+    pub(crate) fn outcome(
+        &self,
+        url: String,
+        status: CacheStatus,
+        timing: Timing,
+        elapsed: Duration,
+        redirects: Vec<Hop>,
+    ) -> FetchOutcome {
+        let mut outcome = FetchOutcome {
+            url,
+            status: self.status,
+            headers: self.headers.clone(),
+            elapsed,
+            timing,
+            size: 0,
+            metadata: Metadata::default(),
+            redirects,
+            body: String::new(),
+            cache: status,
+        };
+        outcome.read_body(&self.body);
+        outcome
+    }
+}
+
+/// The `Cache-Control` directives that matter here.
+#[derive(Debug, Default)]
+struct CacheControl {
+    no_store: bool,
+    no_cache: bool,
+    max_age: Option<Duration>,
+}
+
+impl CacheControl {
+    fn parse(value: Option<&str>) -> Self {
+        let mut control = CacheControl::default();
+        for directive in value.unwrap_or_default().split(',') {
+            let directive = directive.trim().to_ascii_lowercase();
+            match directive.split_once('=') {
+                Some(("max-age", seconds)) => {
+                    control.max_age = seconds.parse().ok().map(Duration::from_secs);
+                }
+                _ if directive == "no-store" => control.no_store = true,
+                _ if directive == "no-cache" => control.no_cache = true,
+                _ => {}
+            }
+        }
+        control
+    }
+}
+
+/// Responses by URL, so fetching a page again can skip the download.
+///
+/// Only successful GET responses the server allows to be stored are kept,
+/// and only if they can be checked later: they carry an `ETag`, a
+/// `Last-Modified` date or a `max-age`. The URL is the only key, so
+/// responses that `Vary` on request headers aren't kept, except on
+/// `Accept-Encoding` which is always sent the same way.
+#[derive(Debug, Clone, Default)]
+pub struct Cache {
+    entries: Arc<Mutex<HashMap<String, Cached>>>,
+    /// Also written here, and read back when not in memory.
+    dir: Option<PathBuf>,
+}
+
+impl Cache {
+    /// A cache that survives restarts by keeping a copy of every response
+    /// in `dir`.
+    pub fn on_disk(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: Some(dir.into()),
+            ..Self::default()
+        }
+    }
+
+    pub(crate) async fn get(&self, url: &str) -> Option<Cached> {
+        if let Some(cached) = self.lock().get(url) {
+            return Some(cached.clone());
+        }
+
+        let (json, body) = paths(self.dir.as_ref()?, url);
+        let json = tokio::fs::read_to_string(json).await.ok()?;
+        let mut cached: Cached = serde_json::from_str(&json).ok()?;
+        // Another URL with the same hash
+        if cached.url != url {
+            return None;
+        }
+        cached.body = tokio::fs::read(body).await.ok()?;
+        self.remember(cached.clone());
+        Some(cached)
+    }
+
+    /// Keep a response, if its headers allow it.
+    pub(crate) async fn store(
+        &self,
+        url: &str,
+        status: u16,
+        headers: &[(String, String)],
+        body: &[u8],
+    ) {
+        let cached = Cached {
+            url: url.to_string(),
+            status,
+            headers: headers.to_vec(),
+            body: body.to_vec(),
+            stored: SystemTime::now(),
+        };
+        let control = CacheControl::parse(cached.header("cache-control"));
+        let checkable = cached.header("etag").is_some()
+            || cached.header("last-modified").is_some()
+            || control.max_age.is_some();
+        let varies = cached.header("vary").is_some_and(|vary| {
+            vary.split(',')
+                .any(|name| !name.trim().eq_ignore_ascii_case("accept-encoding"))
+        });
+        if control.no_store || !checkable || varies {
+            return;
+        }
+        self.save(cached).await;
+    }
+
+    /// Restart the freshness clock of a response the server just confirmed.
+    pub(crate) async fn refresh(&self, mut cached: Cached) {
+        cached.stored = SystemTime::now();
+        self.save(cached).await;
+    }
+
+    async fn save(&self, cached: Cached) {
+        // Failing to write only costs a download next time
+        if let Some(dir) = &self.dir
+            && let Ok(metadata) = serde_json::to_string(&cached)
+            && tokio::fs::create_dir_all(dir).await.is_ok()
+        {
+            let (json, body) = paths(dir, &cached.url);
+            let _ = tokio::fs::write(json, metadata).await;
+            let _ = tokio::fs::write(body, &cached.body).await;
+        }
+        self.remember(cached);
+    }
+
+    fn remember(&self, cached: Cached) {
+        let mut entries = self.lock();
+        if entries.len() == CAPACITY && !entries.contains_key(&cached.url) {
+            let oldest = entries
+                .iter()
+                .min_by_key(|(_, cached)| cached.stored)
+                .map(|(url, _)| url.clone());
+            if let Some(oldest) = oldest {
+                entries.remove(&oldest);
+            }
+        }
+        entries.insert(cached.url.clone(), cached);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, Cached>> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Metadata and body files of `url` in `dir`, named by a hash of the URL
+/// that stays the same across builds (FNV-1a).
+fn paths(dir: &Path, url: &str) -> (PathBuf, PathBuf) {
+    let hash = url.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    (
+        dir.join(format!("{hash:016x}.json")),
+        dir.join(format!("{hash:016x}.body")),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Network, UrlAnalyzer, fetch_url};
+
+    fn block_on<T>(future: impl Future<Output = T>) -> T {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    fn headers(headers: &[(&str, &str)]) -> Vec<(String, String)> {
+        headers
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    /// Whether a 200 with `response_headers` ends up in the cache.
+    fn is_stored(response_headers: &[(&str, &str)]) -> bool {
+        let cache = Cache::default();
+        block_on(async {
+            cache
+                .store("https://a.test/", 200, &headers(response_headers), b"hi")
+                .await;
+            cache.get("https://a.test/").await.is_some()
+        })
+    }
+
+    fn cached(response_headers: &[(&str, &str)], age: Duration) -> Cached {
+        Cached {
+            url: "https://a.test/".to_string(),
+            status: 200,
+            headers: headers(response_headers),
+            body: Vec::new(),
+            stored: SystemTime::now() - age,
+        }
+    }
+
+    #[test]
+    fn stores_only_what_can_be_checked_later() {
+        assert!(is_stored(&[("ETag", "\"v1\"")]));
+        assert!(is_stored(&[(
+            "Last-Modified",
+            "Tue, 01 Sep 2026 10:00:00 GMT"
+        )]));
+        assert!(is_stored(&[("Cache-Control", "public, max-age=60")]));
+        assert!(!is_stored(&[]));
+        assert!(!is_stored(&[("Cache-Control", "no-cache")]));
+    }
+
+    #[test]
+    fn respects_no_store_and_vary() {
+        assert!(!is_stored(&[
+            ("ETag", "\"v1\""),
+            ("Cache-Control", "No-Store")
+        ]));
+        assert!(is_stored(&[
+            ("ETag", "\"v1\""),
+            ("Vary", "Accept-Encoding")
+        ]));
+        assert!(!is_stored(&[
+            ("ETag", "\"v1\""),
+            ("Vary", "Accept-Encoding, Cookie")
+        ]));
+        assert!(!is_stored(&[("ETag", "\"v1\""), ("Vary", "*")]));
+    }
+
+    #[test]
+    fn fresh_until_max_age() {
+        let max_age = [("Cache-Control", "max-age=60")];
+        assert!(cached(&max_age, Duration::ZERO).is_fresh());
+        assert!(!cached(&max_age, Duration::from_secs(61)).is_fresh());
+        assert!(!cached(&[("Cache-Control", "max-age=60, no-cache")], Duration::ZERO).is_fresh());
+        assert!(!cached(&[("ETag", "\"v1\"")], Duration::ZERO).is_fresh());
+    }
+
+    #[test]
+    fn conditional_headers_come_from_the_validators() {
+        let both = cached(
+            &[
+                ("ETag", "\"v1\""),
+                ("Last-Modified", "Tue, 01 Sep 2026 10:00:00 GMT"),
+            ],
+            Duration::ZERO,
+        )
+        .conditional_headers();
+        assert_eq!(both[header::IF_NONE_MATCH], "\"v1\"");
+        assert_eq!(
+            both[header::IF_MODIFIED_SINCE],
+            "Tue, 01 Sep 2026 10:00:00 GMT"
+        );
+
+        let none = cached(&[("Cache-Control", "max-age=60")], Duration::ZERO);
+        assert!(none.conditional_headers().is_empty());
+    }
+
+    #[test]
+    fn disk_entries_are_checked_against_their_url() {
+        let dir = tempfile::tempdir().unwrap();
+        let (url, other) = ("https://a.test/", "https://b.test/");
+        block_on(Cache::on_disk(dir.path()).store(
+            url,
+            200,
+            &headers(&[("ETag", "\"v1\"")]),
+            b"hi",
+        ));
+
+        // A new cache reads it back from disk
+        let cached = block_on(Cache::on_disk(dir.path()).get(url)).unwrap();
+        assert_eq!(cached.body, b"hi");
+
+        // As if `other` had the same hash
+        let (json, body) = paths(dir.path(), url);
+        let (other_json, other_body) = paths(dir.path(), other);
+        std::fs::rename(json, other_json).unwrap();
+        std::fs::rename(body, other_body).unwrap();
+        assert!(block_on(Cache::on_disk(dir.path()).get(other)).is_none());
+    }
+
+    #[test]
+    fn not_modified_serves_the_cached_page() {
+        // Sends the page with an ETag, then 304 to requests that have it
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        std::thread::spawn(move || {
+            use std::io::{Read, Write};
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { break };
+                std::thread::spawn(move || {
+                    let mut buffer = [0; 4096];
+                    while let Ok(n @ 1..) = stream.read(&mut buffer) {
+                        let request = String::from_utf8_lossy(&buffer[..n]).to_lowercase();
+                        let response = if request.contains("if-none-match: \"v1\"") {
+                            "HTTP/1.1 304 Not Modified\r\nETag: \"v1\"\r\n\r\n".to_string()
+                        } else {
+                            let body = "<title>Cached</title>";
+                            format!(
+                                "HTTP/1.1 200 OK\r\nETag: \"v1\"\r\nContent-Type: text/html\r\n\
+                                 Content-Length: {}\r\n\r\n{body}",
+                                body.len()
+                            )
+                        };
+                        if stream.write_all(response.as_bytes()).is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+        });
+
+        let network = Network::default();
+        let request = UrlAnalyzer::default().request(format!("127.0.0.1:{port}"));
+        let (first, second) = block_on(async {
+            let first = fetch_url(&network, request.clone()).await.unwrap();
+            let second = fetch_url(&network, request).await.unwrap();
+            (first, second)
+        });
+
+        assert_eq!(first.cache, CacheStatus::Fetched);
+        assert_eq!(second.cache, CacheStatus::Revalidated);
+        assert_eq!(second.status, 200);
+        assert_eq!(second.metadata.title.as_deref(), Some("Cached"));
+        assert_eq!(second.size, first.size);
+    }
+}
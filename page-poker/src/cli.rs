@@ -0,0 +1,223 @@
+use std::path::PathBuf;
+
+use page_poker::core::{self, Message, Mode, Timeout, UrlAnalyzer};
+use page_poker::{Cache, FetchError, Fetcher, Fixtures, Method, Network, Weight};
+use strum::IntoEnumIterator;
+
+pub const USAGE: &str = "\
+Usage: page-poker [OPTIONS] [URL]...
+
+Without URLs the app window opens, set up with the options given.
+
+Arguments:
+  [URL]...            Pages to fetch, e.g. `example.com`
+
+Options:
+  --mode <MODE>       What to show, e.g. `title`, `metadata`, `headers` or `links` (default: title)
+  --method <METHOD>   Request method, e.g. `head` (default: get)
+  --https             Use HTTPS for URLs without a scheme
+  --timeout <SECS>    Give up on a fetch after SECS seconds (default: 10)
+  --no-redirects      Don't follow redirects
+  --offline <DIR>     Serve requests from saved pages in DIR, e.g. from `wget -r`
+  --cache-dir <DIR>   Keep cached responses in DIR so they outlive the run
+  -h, --help          Print this help";
+
+/// Parsed command line arguments.
+#[derive(Debug, Clone, Default)]
+pub struct Args {
+    pub urls: Vec<String>,
+    pub mode: Mode,
+    pub method: Method,
+    pub https: bool,
+    pub timeout: Timeout,
+    pub no_redirects: bool,
+    pub offline: Option<PathBuf>,
+    pub cache_dir: Option<PathBuf>,
+    pub help: bool,
+}
+
+impl Args {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut parsed = Args::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--mode" => parsed.mode = mode(&value(&arg, args.next())?)?,
+                "--method" => parsed.method = method(&value(&arg, args.next())?)?,
+                "--https" => parsed.https = true,
+                "--timeout" => parsed.timeout = timeout(&value(&arg, args.next())?)?,
+                "--no-redirects" => parsed.no_redirects = true,
+                "--offline" => parsed.offline = Some(PathBuf::from(value(&arg, args.next())?)),
+                "--cache-dir" => {
+                    parsed.cache_dir = Some(PathBuf::from(value(&arg, args.next())?));
+                }
+                "-h" | "--help" => parsed.help = true,
+                flag if flag.starts_with('-') => return Err(format!("unknown option {flag:?}")),
+                _ => parsed.urls.push(arg),
+            }
+        }
+
+        if parsed.offline.is_some() && parsed.cache_dir.is_some() {
+            return Err("--cache-dir can't be used with --offline".to_string());
+        }
+        Ok(parsed)
+    }
+
+    /// A model set up the way the options ask, for either mode.
+    pub fn analyzer(&self) -> UrlAnalyzer {
+        let mut analyzer = UrlAnalyzer::default();
+        let settings = [
+            Message::ModeChanged(self.mode),
+            Message::MethodChanged(self.method),
+            Message::SecureChanged(self.https),
+            Message::TimeoutChanged(self.timeout),
+            Message::FollowRedirectsChanged(!self.no_redirects),
+        ];
+        for message in settings {
+            let _ = analyzer.update(message);
+        }
+        analyzer
+    }
+
+    /// Fetches over the network, with the cache in `--cache-dir` if given.
+    pub fn network(&self) -> Network {
+        match &self.cache_dir {
+            Some(dir) => Network::with_cache(Cache::on_disk(dir)),
+            None => Network::default(),
+        }
+    }
+}
+
+fn value(flag: &str, value: Option<String>) -> Result<String, String> {
+    value.ok_or_else(|| format!("{flag} requires a value"))
+}
+
+/// `Download Time` is accepted as `download-time`, `downloadtime`, ... and
+/// `Links & Assets` also as `crawl`.
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn mode(name: &str) -> Result<Mode, String> {
+    Mode::iter()
+        .find(|mode| {
+            let names = [mode.to_string(), format!("{mode:?}")];
+            names.iter().any(|known| normalize(known) == normalize(name))
+                // `Links & Assets` and `Redirects & Headers` by either half
+                || mode
+                    .to_string()
+                    .split(" & ")
+                    .any(|half| normalize(half) == normalize(name))
+        })
+        .ok_or_else(|| {
+            let valid: Vec<String> = Mode::iter()
+                .map(|mode| {
+                    let name = mode.to_string().to_lowercase();
+                    name.replace(" & ", "-").replace(' ', "-")
+                })
+                .collect();
+            format!("unknown mode {name:?} (valid: {})", valid.join(", "))
+        })
+}
+
+fn method(name: &str) -> Result<Method, String> {
+    Method::iter()
+        .find(|method| method.to_string().eq_ignore_ascii_case(name))
+        .ok_or_else(|| format!("unknown method {name:?}"))
+}
+
+fn timeout(secs: &str) -> Result<Timeout, String> {
+    match secs.parse() {
+        Ok(secs) if secs > 0 => Ok(Timeout(secs)),
+        _ => Err(format!(
+            "invalid timeout {secs:?} (expected a whole number of seconds above 0)"
+        )),
+    }
+}
+
+/// Fetch every URL and print what the mode is about. Returns whether all
+/// of them could be fetched.
+pub fn run(args: &Args) -> Result<bool, String> {
+    let fetcher: Box<dyn Fetcher> = match &args.offline {
+        Some(dir) => Box::new(Fixtures::dir(dir)),
+        None => Box::new(args.network()),
+    };
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| format!("can't start the async runtime: {e}"))?;
+
+    let mut analyzer = args.analyzer();
+
+    let mut ok = true;
+    for (index, url) in args.urls.iter().enumerate() {
+        // Separated like `head` does with several files
+        if args.urls.len() > 1 {
+            if index > 0 {
+                println!();
+            }
+            println!("==> {url} <==");
+        }
+
+        let _ = analyzer.update(Message::SecureChanged(args.https));
+        let _ = analyzer.update(Message::UrlChanged(url.clone()));
+        if let Some(error) = analyzer.url_error() {
+            eprintln!("error: {url}: {error}");
+            ok = false;
+            continue;
+        }
+        runtime.block_on(core::run(&mut analyzer, fetcher.as_ref(), Message::Fetch));
+        if analyzer.mode() == Mode::Crawl {
+            runtime.block_on(core::run(
+                &mut analyzer,
+                fetcher.as_ref(),
+                Message::FetchAssets,
+            ));
+        }
+
+        match analyzer.result() {
+            Some(Ok(outcome)) if analyzer.mode() == Mode::Crawl => {
+                print_assets(&analyzer, outcome.size);
+                eprintln!("{}", core::summary(outcome));
+            }
+            Some(Ok(outcome)) => {
+                println!("{}", core::describe(outcome, analyzer.mode()));
+                eprintln!("{}", core::summary(outcome));
+            }
+            Some(Err(error)) => {
+                eprintln!("error: {url}: {error}");
+                ok = false;
+            }
+            None => {}
+        }
+    }
+    Ok(ok)
+}
+
+/// One line per asset with how fetching it went, then the page weight.
+fn print_assets(analyzer: &UrlAnalyzer, document: usize) {
+    let size = |bytes: usize| humansize::format_size(bytes, humansize::DECIMAL);
+    for asset in analyzer.assets() {
+        let (status, detail) = match &asset.checked {
+            Some(Ok(snapshot)) => (snapshot.status.to_string(), size(snapshot.size)),
+            Some(Err(FetchError::Status { code, reason })) => (code.to_string(), reason.clone()),
+            Some(Err(error)) => ("Error".to_string(), error.to_string()),
+            None => ("—".to_string(), String::new()),
+        };
+        let kind = format!("{:?}", asset.kind);
+        println!("{status:>5}  {kind:<10}  {}  {detail}", asset.url);
+    }
+
+    let weight = Weight::of(document, analyzer.assets());
+    println!(
+        "Page weight: {} · {} of {} checked · {} broken",
+        size(weight.bytes),
+        weight.checked,
+        analyzer.assets().len(),
+        weight.broken
+    );
+}
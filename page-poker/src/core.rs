@@ -0,0 +1,715 @@
+//! The model of Page Poker and how it changes, without any UI.
+//!
+//! [`UrlAnalyzer::update`] handles a [`Message`] and returns the [`Effect`]
+//! it asks for, e.g. a fetch to start. Whoever drives it decides how to carry
+//! that out: the iced view runs fetches as abortable tasks, a command line
+//! tool can simply [`run`] them one after another.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter};
+
+use crate::{
+    Asset, BatchRow, BodyKind, Column, FetchError, FetchOutcome, Fetcher, Method, Request,
+    Snapshot, Sort, fetch_url, html, split_scheme,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Display, EnumIter, Serialize, Deserialize)]
+pub enum Mode {
+    #[default]
+    Title,
+
+    Metadata,
+
+    #[strum(serialize = "Download Time")]
+    DownloadTime,
+
+    #[strum(serialize = "Download Size")]
+    DownloadSize,
+
+    #[strum(serialize = "Redirects & Headers")]
+    Headers,
+
+    #[strum(serialize = "Response Body")]
+    Body,
+
+    #[strum(serialize = "Links & Assets")]
+    Crawl,
+}
+
+/// How long a fetch may take before it is given up, in seconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timeout(pub u64);
+
+impl Timeout {
+    pub const ALL: [Timeout; 5] = [
+        Timeout(2),
+        Timeout(5),
+        Timeout(10),
+        Timeout(30),
+        Timeout(60),
+    ];
+
+    pub fn duration(self) -> Duration {
+        Duration::from_secs(self.0)
+    }
+}
+
+impl Default for Timeout {
+    fn default() -> Self {
+        Timeout(10)
+    }
+}
+
+impl fmt::Display for Timeout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}s", self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UrlAnalyzer {
+    pub(crate) method: Method,
+    pub(crate) url: String,
+    pub(crate) secure: bool,
+    pub(crate) mode: Mode,
+    pub(crate) timeout: Timeout,
+    pub(crate) follow_redirects: bool,
+    pub(crate) use_cache: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) headers: Vec<(String, String)>,
+    pub(crate) body_kind: BodyKind,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub(crate) body: String,
+    /// Fetch every URL in `urls` instead of just `url`.
+    pub(crate) batch: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) urls: Vec<String>,
+    pub(crate) sort: Sort,
+
+    /// Shared so copies of the model, like the debugger snapshots, don't
+    /// copy the body.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) result: Option<Arc<Result<FetchOutcome, FetchError>>>,
+
+    /// Found in `result` by the Links & Assets mode.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) assets: Vec<Asset>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) rows: Vec<BatchRow>,
+
+    #[serde(skip)]
+    pub(crate) loading: bool,
+}
+
+impl Default for UrlAnalyzer {
+    fn default() -> Self {
+        Self {
+            method: Method::default(),
+            url: String::new(),
+            secure: false,
+            mode: Mode::default(),
+            timeout: Timeout::default(),
+            follow_redirects: true,
+            use_cache: true,
+            headers: Vec::new(),
+            body_kind: BodyKind::default(),
+            body: String::new(),
+            batch: false,
+            urls: Vec::new(),
+            sort: Sort::default(),
+            result: None,
+            assets: Vec::new(),
+            rows: Vec::new(),
+            loading: false,
+        }
+    }
+}
+
+/// A change to the model, from the user or from a finished fetch.
+#[derive(Debug, Clone)]
+pub enum Message {
+    MethodChanged(Method),
+    UrlChanged(String),
+    SecureChanged(bool),
+    ModeChanged(Mode),
+    TimeoutChanged(Timeout),
+    FollowRedirectsChanged(bool),
+    UseCacheChanged(bool),
+    BatchChanged(bool),
+    /// The URLs of batch mode.
+    UrlsChanged(Vec<String>),
+    SortBy(Column),
+    AddHeader,
+    RemoveHeader(usize),
+    HeaderNameChanged(usize, String),
+    HeaderValueChanged(usize, String),
+    BodyKindChanged(BodyKind),
+    BodyChanged(String),
+    /// Fetch the URL, or every URL in batch mode.
+    Fetch,
+    Cancelled,
+    Fetched(Box<Result<FetchOutcome, FetchError>>),
+    RowFetched(usize, Box<Result<FetchOutcome, FetchError>>),
+    /// Fetch the assets of the Links & Assets mode not checked yet.
+    FetchAssets,
+    AssetFetched(usize, Box<Result<FetchOutcome, FetchError>>),
+}
+
+/// What [`UrlAnalyzer::update`] asks its caller to do. Each fetch is to be
+/// answered with the message named below.
+#[derive(Debug, Clone, PartialEq)]
+#[must_use]
+pub enum Effect {
+    None,
+    /// Answered with [`Message::Fetched`].
+    Fetch(Request),
+    /// Batch rows by index, answered with [`Message::RowFetched`].
+    FetchRows(Vec<(usize, Request)>),
+    /// Assets by index, answered with [`Message::AssetFetched`].
+    FetchAssets(Vec<(usize, Request)>),
+}
+
+impl UrlAnalyzer {
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// The URL as typed, without its scheme.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn is_loading(&self) -> bool {
+        self.loading
+    }
+
+    /// The last fetch of a single URL, once it is done.
+    pub fn result(&self) -> Option<&Result<FetchOutcome, FetchError>> {
+        self.result.as_deref()
+    }
+
+    pub fn assets(&self) -> &[Asset] {
+        &self.assets
+    }
+
+    pub fn rows(&self) -> &[BatchRow] {
+        &self.rows
+    }
+
+    /// Why the URL can't be fetched. An empty URL isn't worth an error
+    /// until it is fetched.
+    pub fn url_error(&self) -> Option<FetchError> {
+        if self.url.trim().is_empty() {
+            None
+        } else {
+            self.request(self.url.clone()).full_url().err()
+        }
+    }
+
+    pub fn can_fetch(&self) -> bool {
+        if self.batch {
+            !self.urls.is_empty()
+        } else {
+            !self.url.trim().is_empty() && self.url_error().is_none()
+        }
+    }
+
+    /// A request for `url` with the method, headers and body set here.
+    pub fn request(&self, url: String) -> Request {
+        Request {
+            method: self.method,
+            url,
+            secure: self.secure,
+            timeout: self.timeout.duration(),
+            follow_redirects: self.follow_redirects,
+            use_cache: self.use_cache,
+            headers: self.headers.clone(),
+            body_kind: self.body_kind,
+            body: self.body.clone(),
+        }
+    }
+
+    pub fn update(&mut self, message: Message) -> Effect {
+        match message {
+            Message::MethodChanged(method) => self.method = method,
+            Message::UrlChanged(url) => {
+                // A typed or pasted scheme moves to the HTTPS checkbox, so
+                // the two can't disagree
+                match split_scheme(url.trim_start()) {
+                    Some((secure, rest)) => {
+                        self.secure = secure;
+                        self.url = rest.to_string();
+                    }
+                    None => self.url = url,
+                }
+            }
+            Message::SecureChanged(secure) => self.secure = secure,
+            Message::ModeChanged(mode) => {
+                self.mode = mode;
+                self.find_assets();
+            }
+            Message::TimeoutChanged(timeout) => self.timeout = timeout,
+            Message::FollowRedirectsChanged(follow) => self.follow_redirects = follow,
+            Message::UseCacheChanged(use_cache) => self.use_cache = use_cache,
+            Message::BatchChanged(batch) => self.batch = batch,
+            Message::UrlsChanged(urls) => self.urls = urls,
+            Message::SortBy(column) => self.sort = self.sort.by(column),
+            Message::AddHeader => self.headers.push((String::new(), String::new())),
+            Message::RemoveHeader(index) => {
+                if index < self.headers.len() {
+                    self.headers.remove(index);
+                }
+            }
+            Message::HeaderNameChanged(index, name) => {
+                if let Some(header) = self.headers.get_mut(index) {
+                    header.0 = name;
+                }
+            }
+            Message::HeaderValueChanged(index, value) => {
+                if let Some(header) = self.headers.get_mut(index) {
+                    header.1 = value;
+                }
+            }
+            Message::BodyKindChanged(kind) => self.body_kind = kind,
+            Message::BodyChanged(body) => self.body = body,
+            Message::Fetch if self.batch => {
+                if self.loading || self.urls.is_empty() {
+                    return Effect::None;
+                }
+                self.loading = true;
+                self.rows = self
+                    .urls
+                    .iter()
+                    .map(|url| BatchRow {
+                        url: url.clone(),
+                        result: None,
+                    })
+                    .collect();
+                return Effect::FetchRows(
+                    self.urls
+                        .iter()
+                        .enumerate()
+                        .map(|(index, url)| (index, self.request(url.clone())))
+                        .collect(),
+                );
+            }
+            Message::Fetch => {
+                if self.loading || self.url_error().is_some() {
+                    return Effect::None;
+                }
+                self.loading = true;
+                self.result = None;
+                self.assets.clear();
+                return Effect::Fetch(self.request(self.url.clone()));
+            }
+            Message::Cancelled => self.loading = false,
+            Message::Fetched(result) => {
+                self.loading = false;
+                self.result = Some(Arc::new(*result));
+                self.find_assets();
+            }
+            // Cancelling drops the fetches still running, so rows without a
+            // result are only waited for while loading
+            Message::RowFetched(index, result) => {
+                if let Some(row) = self.rows.get_mut(index) {
+                    row.result = Some(Arc::new(*result));
+                }
+                self.loading = self.rows.iter().any(|row| row.result.is_none());
+            }
+            Message::FetchAssets => {
+                if self.loading {
+                    return Effect::None;
+                }
+                let requests: Vec<(usize, Request)> = self
+                    .assets
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, asset)| asset.checked.is_none())
+                    .map(|(index, asset)| {
+                        let method = if asset.head_refused {
+                            Method::Get
+                        } else {
+                            Method::Head
+                        };
+                        (index, self.asset_request(asset.url.clone(), method))
+                    })
+                    .collect();
+                self.loading = !requests.is_empty();
+                return Effect::FetchAssets(requests);
+            }
+            Message::AssetFetched(index, result) => {
+                let Some(asset) = self.assets.get_mut(index) else {
+                    return Effect::None;
+                };
+                match *result {
+                    // Not every server supports HEAD
+                    Err(FetchError::Status {
+                        code: 405 | 501, ..
+                    }) if !asset.head_refused => {
+                        asset.head_refused = true;
+                        let url = asset.url.clone();
+                        return Effect::FetchAssets(vec![(
+                            index,
+                            self.asset_request(url, Method::Get),
+                        )]);
+                    }
+                    Ok(outcome) => {
+                        let mut snapshot = Snapshot::from(&outcome);
+                        if !asset.head_refused {
+                            snapshot.size = announced_size(&outcome);
+                        }
+                        asset.checked = Some(Ok(snapshot));
+                    }
+                    Err(error) => asset.checked = Some(Err(error)),
+                }
+                self.loading = self.assets.iter().any(|asset| asset.checked.is_none());
+            }
+        }
+        Effect::None
+    }
+
+    /// Assets are fetched like the page, but without headers or a body.
+    /// They are only checked, so a HEAD does unless the server refuses it.
+    fn asset_request(&self, url: String, method: Method) -> Request {
+        Request {
+            method,
+            headers: Vec::new(),
+            body_kind: BodyKind::None,
+            body: String::new(),
+            ..self.request(url)
+        }
+    }
+
+    /// List the assets of the fetched page, once the Links & Assets mode
+    /// wants them.
+    fn find_assets(&mut self) {
+        if self.mode != Mode::Crawl || !self.assets.is_empty() {
+            return;
+        }
+        if let Some(Ok(outcome)) = self.result.as_deref() {
+            let base = reqwest::Url::parse(&outcome.url).ok();
+            self.assets = html::assets(&outcome.body, base.as_ref())
+                .into_iter()
+                .map(|(kind, url)| Asset {
+                    kind,
+                    url,
+                    checked: None,
+                    head_refused: false,
+                })
+                .collect();
+        }
+    }
+}
+
+/// The `Content-Length` of a response to HEAD, which has no body to
+/// measure, if the server sent one.
+fn announced_size(outcome: &FetchOutcome) -> usize {
+    outcome
+        .header("content-length")
+        .and_then(|length| length.trim().parse().ok())
+        .unwrap_or(outcome.size)
+}
+
+/// Handle `message` and every fetch it leads to, one after another, until
+/// there is nothing left to do. For tools without a UI; the iced view runs
+/// its fetches concurrently and can cancel them.
+pub async fn run(analyzer: &mut UrlAnalyzer, fetcher: &dyn Fetcher, message: Message) {
+    let mut pending = VecDeque::from([message]);
+    while let Some(message) = pending.pop_front() {
+        match analyzer.update(message) {
+            Effect::None => {}
+            Effect::Fetch(request) => {
+                let result = fetch_url(fetcher, request).await;
+                pending.push_back(Message::Fetched(Box::new(result)));
+            }
+            Effect::FetchRows(requests) => {
+                for (index, request) in requests {
+                    let result = fetch_url(fetcher, request).await;
+                    pending.push_back(Message::RowFetched(index, Box::new(result)));
+                }
+            }
+            Effect::FetchAssets(requests) => {
+                for (index, request) in requests {
+                    let result = fetch_url(fetcher, request).await;
+                    pending.push_back(Message::AssetFetched(index, Box::new(result)));
+                }
+            }
+        }
+    }
+}
+
+/// The part of a fetch the selected mode is about.
+pub fn describe(outcome: &FetchOutcome, mode: Mode) -> String {
+    match mode {
+        Mode::Title => match &outcome.metadata.title {
+            Some(title) => format!("Title: {title}"),
+            None => "No <title> found".to_string(),
+        },
+        Mode::Metadata => {
+            let metadata = &outcome.metadata;
+            let field =
+                |value: &Option<String>| value.clone().unwrap_or_else(|| "(none)".to_string());
+            let mut lines = vec![
+                format!("Title: {}", field(&metadata.title)),
+                format!("Description: {}", field(&metadata.description)),
+                format!("Canonical: {}", field(&metadata.canonical)),
+                format!("Favicon: {}", field(&metadata.favicon)),
+            ];
+            lines.extend(
+                metadata
+                    .open_graph
+                    .iter()
+                    .map(|(property, content)| format!("{property}: {content}")),
+            );
+            lines.join("\n")
+        }
+        Mode::DownloadTime => {
+            let mut text = format!("Download time: {:.2?}", outcome.elapsed);
+            if outcome.timing.dns.is_none() {
+                text.push_str(" (reused connection)");
+            }
+            text
+        }
+        Mode::DownloadSize => format!(
+            "Size: {}",
+            humansize::format_size(outcome.size, humansize::DECIMAL)
+        ),
+        Mode::Headers => {
+            let mut lines: Vec<String> = outcome
+                .redirects
+                .iter()
+                .map(|hop| format!("{} {} → {}", hop.status, hop.url, hop.location))
+                .collect();
+            lines.extend(
+                outcome
+                    .headers
+                    .iter()
+                    .map(|(name, value)| format!("{name}: {value}")),
+            );
+            lines.join("\n")
+        }
+        Mode::Body => outcome.body.clone(),
+        Mode::Crawl => {
+            let base = reqwest::Url::parse(&outcome.url).ok();
+            html::assets(&outcome.body, base.as_ref())
+                .into_iter()
+                .map(|(kind, url)| format!("{kind:?}: {url}"))
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+    }
+}
+
+/// One-line overview shown under every result, e.g.
+/// `200 · text/html · 1.26 kB · 212ms · cache hit`.
+pub fn summary(outcome: &FetchOutcome) -> String {
+    let mut parts = vec![outcome.status.to_string()];
+    if let Some(content_type) = outcome.header("content-type") {
+        parts.push(content_type.to_string());
+    }
+    parts.push(humansize::format_size(outcome.size, humansize::DECIMAL));
+    parts.push(format!("{:.0?}", outcome.elapsed));
+    parts.push(outcome.cache.to_string());
+    parts.join(" · ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AssetKind, Canned, Fixtures};
+
+    fn run_with(analyzer: &mut UrlAnalyzer, fixtures: &Fixtures, message: Message) {
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(run(analyzer, fixtures, message));
+    }
+
+    fn site() -> Fixtures {
+        Fixtures::default()
+            .with(
+                "http://site.test/",
+                Canned::html(
+                    r#"<title>Site</title>
+                    <link rel="stylesheet" href="/style.css">
+                    <img src="/logo.png">
+                    <a href="/gone">Gone</a>"#,
+                ),
+            )
+            .with("http://site.test/style.css", Canned::html("body {}"))
+            // Refuses HEAD and GET alike, fixtures don't look at the method
+            .with("http://site.test/logo.png", Canned::status(501))
+            .with("http://site.test/gone", Canned::status(404))
+    }
+
+    #[test]
+    fn typed_scheme_moves_to_the_https_checkbox() {
+        let mut analyzer = UrlAnalyzer::default();
+
+        let _ = analyzer.update(Message::UrlChanged("  HTTPS://example.com/a".to_string()));
+        assert!(analyzer.secure);
+        assert_eq!(analyzer.url(), "example.com/a");
+
+        let _ = analyzer.update(Message::UrlChanged("http://example.com/b".to_string()));
+        assert!(!analyzer.secure);
+        assert_eq!(analyzer.url(), "example.com/b");
+
+        // Without a scheme the checkbox stays as it is
+        let _ = analyzer.update(Message::SecureChanged(true));
+        let _ = analyzer.update(Message::UrlChanged("example.com/c".to_string()));
+        assert!(analyzer.secure);
+        assert_eq!(analyzer.url(), "example.com/c");
+    }
+
+    #[test]
+    fn batch_loads_until_every_row_is_back() {
+        let mut analyzer = UrlAnalyzer::default();
+        let _ = analyzer.update(Message::BatchChanged(true));
+        let _ = analyzer.update(Message::UrlsChanged(vec![
+            "a.test".to_string(),
+            "b.test".to_string(),
+        ]));
+
+        let Effect::FetchRows(requests) = analyzer.update(Message::Fetch) else {
+            panic!("expected a fetch per row");
+        };
+        let urls: Vec<_> = requests
+            .iter()
+            .map(|(index, request)| (*index, request.url.as_str()))
+            .collect();
+        assert_eq!(urls, [(0, "a.test"), (1, "b.test")]);
+        assert!(analyzer.is_loading());
+        assert_eq!(analyzer.update(Message::Fetch), Effect::None);
+
+        let _ = analyzer.update(Message::RowFetched(1, Box::new(Err(FetchError::Timeout))));
+        assert!(analyzer.is_loading());
+        let _ = analyzer.update(Message::RowFetched(0, Box::new(Err(FetchError::Timeout))));
+        assert!(!analyzer.is_loading());
+    }
+
+    #[test]
+    fn batch_runs_against_fixtures() {
+        let fixtures = Fixtures::default()
+            .with("http://a.test/", Canned::html("<title>A</title>"))
+            .with("http://b.test/", Canned::status(404));
+        let mut analyzer = UrlAnalyzer::default();
+        let _ = analyzer.update(Message::BatchChanged(true));
+        let _ = analyzer.update(Message::UrlsChanged(vec![
+            "a.test".to_string(),
+            "b.test".to_string(),
+        ]));
+
+        run_with(&mut analyzer, &fixtures, Message::Fetch);
+
+        assert!(!analyzer.is_loading());
+        let rows = analyzer.rows();
+        assert!(matches!(rows[0].result.as_deref(), Some(Ok(outcome)) if outcome.status == 200));
+        assert!(matches!(
+            rows[1].result.as_deref(),
+            Some(Err(FetchError::Status { code: 404, .. }))
+        ));
+    }
+
+    #[test]
+    fn links_and_assets_are_found_and_checked() {
+        let mut analyzer = UrlAnalyzer::default();
+        let _ = analyzer.update(Message::ModeChanged(Mode::Crawl));
+        let _ = analyzer.update(Message::UrlChanged("site.test".to_string()));
+
+        run_with(&mut analyzer, &site(), Message::Fetch);
+        let found: Vec<_> = analyzer
+            .assets()
+            .iter()
+            .map(|asset| (asset.kind, asset.url.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (AssetKind::Stylesheet, "http://site.test/style.css"),
+                (AssetKind::Image, "http://site.test/logo.png"),
+                (AssetKind::Link, "http://site.test/gone"),
+            ]
+        );
+
+        run_with(&mut analyzer, &site(), Message::FetchAssets);
+        assert!(!analyzer.is_loading());
+        let [style, logo, gone] = analyzer.assets() else {
+            panic!("expected three assets");
+        };
+        assert!(matches!(&style.checked, Some(Ok(snapshot)) if snapshot.status == 200));
+        // Refused with a HEAD, then failed with a GET too
+        assert!(logo.head_refused);
+        assert!(matches!(
+            logo.checked,
+            Some(Err(FetchError::Status { code: 501, .. }))
+        ));
+        assert!(!gone.head_refused);
+        assert!(matches!(
+            gone.checked,
+            Some(Err(FetchError::Status { code: 404, .. }))
+        ));
+    }
+
+    #[test]
+    fn assets_fall_back_to_get_when_head_is_refused() {
+        let mut analyzer = UrlAnalyzer::default();
+        let _ = analyzer.update(Message::ModeChanged(Mode::Crawl));
+        let _ = analyzer.update(Message::UrlChanged("site.test".to_string()));
+        run_with(&mut analyzer, &site(), Message::Fetch);
+
+        let Effect::FetchAssets(requests) = analyzer.update(Message::FetchAssets) else {
+            panic!("expected the assets to be fetched");
+        };
+        assert_eq!(requests.len(), 3);
+        assert!(
+            requests
+                .iter()
+                .all(|(_, request)| request.method == Method::Head)
+        );
+        assert!(analyzer.is_loading());
+
+        let refused = Err(FetchError::Status {
+            code: 405,
+            reason: "Method Not Allowed".to_string(),
+        });
+        let retry = analyzer.update(Message::AssetFetched(1, Box::new(refused)));
+        let Effect::FetchAssets(retry) = retry else {
+            panic!("expected a GET for the refused asset");
+        };
+        assert_eq!(retry.len(), 1);
+        assert_eq!(retry[0].0, 1);
+        assert_eq!(retry[0].1.method, Method::Get);
+        assert!(analyzer.is_loading());
+
+        for index in [0, 2, 1] {
+            let _ = analyzer.update(Message::AssetFetched(
+                index,
+                Box::new(Err(FetchError::Timeout)),
+            ));
+        }
+        assert!(!analyzer.is_loading());
+        assert!(analyzer.assets()[1].head_refused);
+    }
+
+    #[test]
+    fn cancelling_stops_loading() {
+        let mut analyzer = UrlAnalyzer::default();
+        let _ = analyzer.update(Message::UrlChanged("site.test".to_string()));
+
+        assert!(matches!(analyzer.update(Message::Fetch), Effect::Fetch(_)));
+        assert!(analyzer.is_loading());
+        let _ = analyzer.update(Message::Cancelled);
+        assert!(!analyzer.is_loading());
+        assert!(analyzer.result().is_none());
+
+        // And the next fetch can start
+        assert!(matches!(analyzer.update(Message::Fetch), Effect::Fetch(_)));
+    }
+}
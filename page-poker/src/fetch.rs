@@ -0,0 +1,719 @@
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use futures::channel::mpsc;
+use futures::future::BoxFuture;
+use futures::{FutureExt, SinkExt, Stream, StreamExt, stream};
+use serde::{Deserialize, Serialize};
+
+use crate::timing::{self, Timing};
+use crate::{BodyKind, Cache, CacheStatus, Metadata, Method};
+
+/// Redirects followed before giving up, same as browsers' usual limit.
+pub(crate) const MAX_REDIRECTS: usize = 20;
+
+/// How often progress is reported while the body downloads.
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Dropped from the request when a redirect turns it into a GET.
+const BODY_HEADERS: [reqwest::header::HeaderName; 4] = [
+    reqwest::header::CONTENT_TYPE,
+    reqwest::header::CONTENT_LENGTH,
+    reqwest::header::CONTENT_ENCODING,
+    reqwest::header::TRANSFER_ENCODING,
+];
+
+/// Dropped from the request when a redirect leads to another origin.
+const CREDENTIAL_HEADERS: [reqwest::header::HeaderName; 3] = [
+    reqwest::header::AUTHORIZATION,
+    reqwest::header::COOKIE,
+    reqwest::header::PROXY_AUTHORIZATION,
+];
+
+/// Response bodies longer than this are cut short when kept as text.
+const BODY_LIMIT: usize = 256 * 1024;
+
+/// Reports body download progress to whoever started the fetch.
+pub type Reporter = Box<dyn FnMut(Progress) + Send>;
+
+/// Where pages come from. [`Network`] is the real thing, [`Fixtures`]
+/// answers from canned responses for offline demos and tests.
+///
+/// [`Fixtures`]: crate::Fixtures
+pub trait Fetcher: Send + Sync {
+    /// Fetch `request`, calling `progress` while the body downloads.
+    fn fetch(
+        &self,
+        request: Request,
+        progress: Reporter,
+    ) -> BoxFuture<'static, Result<FetchOutcome, FetchError>>;
+}
+
+/// What to fetch and how, as set in the UI.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Request {
+    pub method: Method,
+    /// As typed, possibly without a scheme.
+    pub url: String,
+    /// Use `https://` when `url` has no scheme.
+    pub secure: bool,
+    /// For the whole fetch, redirects included.
+    pub timeout: Duration,
+    /// With this off the first response is final, even a redirect.
+    pub follow_redirects: bool,
+    /// Answer GET requests from the cache when possible. Requests with
+    /// headers always go to the server.
+    pub use_cache: bool,
+    /// Sent as is; rows without a name are skipped.
+    pub headers: Vec<(String, String)>,
+    pub body_kind: BodyKind,
+    /// JSON text, or `name=value` lines for a form.
+    pub body: String,
+}
+
+impl Request {
+    /// The URL to fetch, prefixing `http://` or `https://` when no scheme
+    /// is given.
+    pub fn full_url(&self) -> Result<reqwest::Url, FetchError> {
+        let url = self.url.trim();
+        if url.is_empty() {
+            return Err(FetchError::MissingUrl);
+        }
+
+        let protocol = if self.secure { "https" } else { "http" };
+        let full_url = match (split_scheme(url), scheme(url)) {
+            (Some(_), _) => url.to_string(),
+            (None, Some((scheme, _))) => {
+                return Err(FetchError::InvalidUrl(format!(
+                    "{scheme}:// isn't supported, only http:// and https://"
+                )));
+            }
+            (None, None) => format!("{protocol}://{url}"),
+        };
+        reqwest::Url::parse(&full_url).map_err(|e| FetchError::InvalidUrl(e.to_string()))
+    }
+
+    /// The headers to send, checked.
+    pub fn header_map(&self) -> Result<reqwest::header::HeaderMap, FetchError> {
+        use reqwest::header::{HeaderName, HeaderValue};
+
+        let mut map = reqwest::header::HeaderMap::new();
+        for (name, value) in &self.headers {
+            if name.trim().is_empty() {
+                continue;
+            }
+            let name = HeaderName::from_bytes(name.trim().as_bytes())
+                .map_err(|_| FetchError::InvalidRequest(format!("bad header name {name:?}")))?;
+            let value = HeaderValue::from_str(value.trim())
+                .map_err(|_| FetchError::InvalidRequest(format!("bad value for header {name}")))?;
+            map.append(name, value);
+        }
+        Ok(map)
+    }
+
+    /// Content type and bytes of the body, if it has one. JSON is checked
+    /// before it is sent, form lines are URL-encoded.
+    pub fn encoded_body(&self) -> Result<Option<(&'static str, Vec<u8>)>, FetchError> {
+        match self.body_kind {
+            BodyKind::None => Ok(None),
+            BodyKind::Json => {
+                serde_json::from_str::<serde_json::Value>(&self.body)
+                    .map_err(|e| FetchError::InvalidRequest(format!("body isn't JSON: {e}")))?;
+                Ok(Some(("application/json", self.body.clone().into_bytes())))
+            }
+            BodyKind::Form => {
+                let pairs = self
+                    .body
+                    .lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty())
+                    .map(|line| line.split_once('=').unwrap_or((line, "")));
+                let encoded = form_urlencoded::Serializer::new(String::new())
+                    .extend_pairs(pairs)
+                    .finish();
+                Ok(Some((
+                    "application/x-www-form-urlencoded",
+                    encoded.into_bytes(),
+                )))
+            }
+        }
+    }
+}
+
+/// Split an explicit `http://` or `https://` off `url`, along with whether
+/// it was the secure one.
+pub fn split_scheme(url: &str) -> Option<(bool, &str)> {
+    let (scheme, rest) = scheme(url)?;
+    match scheme.to_ascii_lowercase().as_str() {
+        "http" => Some((false, rest)),
+        "https" => Some((true, rest)),
+        _ => None,
+    }
+}
+
+/// Split off whatever scheme `url` starts with. A `://` further along, like
+/// in `example.com/login?next=https://example.org`, is not one: a scheme is
+/// a letter followed by letters, digits, `+`, `-` and `.` (RFC 3986).
+fn scheme(url: &str) -> Option<(&str, &str)> {
+    let (scheme, rest) = url.split_once("://")?;
+    let mut chars = scheme.chars();
+    let valid = chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    valid.then_some((scheme, rest))
+}
+
+/// A successfully fetched page.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FetchOutcome {
+    /// Final URL after redirects.
+    pub url: String,
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    /// Time until the whole body was received, redirects included.
+    pub elapsed: Duration,
+    #[serde(default)]
+    pub timing: Timing,
+    /// Body size in bytes.
+    pub size: usize,
+    /// Parsed from the body when it is HTML, empty otherwise.
+    pub metadata: Metadata,
+    /// Redirect responses that led to the final one, in order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub redirects: Vec<Hop>,
+    /// The body as text, empty when it isn't text. Too big for the saved
+    /// state, so it is only kept in memory.
+    #[serde(skip)]
+    pub body: String,
+    #[serde(default)]
+    pub cache: CacheStatus,
+}
+
+/// One redirect response on the way to the final URL.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Hop {
+    pub url: String,
+    pub status: u16,
+    pub location: String,
+    /// Time from sending the request until this response arrived.
+    pub elapsed: Duration,
+}
+
+/// How far the body download has got.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Progress {
+    /// Bytes received so far.
+    pub received: usize,
+    /// From `Content-Length`, when the server sent one.
+    pub total: Option<u64>,
+    /// Since the response headers arrived.
+    pub elapsed: Duration,
+}
+
+impl Progress {
+    /// Bytes per second so far.
+    pub fn rate(&self) -> f64 {
+        let seconds = self.elapsed.as_secs_f64();
+        if seconds > 0.0 {
+            self.received as f64 / seconds
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Items of [`fetch_stream`]: some progress, then exactly one result.
+#[derive(Debug, Clone)]
+pub enum Update {
+    Progress(Progress),
+    Finished(Box<Result<FetchOutcome, FetchError>>),
+}
+
+impl FetchOutcome {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        header(&self.headers, name)
+    }
+
+    /// Fill in `size`, `body` when it is text and, when it is HTML,
+    /// `metadata`.
+    pub(crate) fn read_body(&mut self, body: &[u8]) {
+        self.size = body.len();
+        let content_type = self.header("content-type").unwrap_or("text/html");
+        let is_text = ["text", "json", "xml", "javascript", "html"]
+            .iter()
+            .any(|kind| content_type.contains(kind));
+        if !is_text {
+            return;
+        }
+
+        let text = String::from_utf8_lossy(body);
+        if content_type.contains("html") {
+            let base = reqwest::Url::parse(&self.url).ok();
+            self.metadata = Metadata::parse(&text, base.as_ref());
+        }
+        self.body = match text.char_indices().nth(BODY_LIMIT) {
+            Some((end, _)) => format!("{}\n… cut at {BODY_LIMIT} characters", &text[..end]),
+            None => text.into_owned(),
+        };
+    }
+}
+
+/// Value of the first header called `name`, ignoring case.
+pub(crate) fn header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(header, _)| header.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+/// 4xx and 5xx responses are errors.
+pub(crate) fn check_status(code: u16) -> Result<(), FetchError> {
+    let status = reqwest::StatusCode::from_u16(code)
+        .map_err(|_| FetchError::Other(format!("invalid status {code}")))?;
+    if status.is_client_error() || status.is_server_error() {
+        return Err(FetchError::Status {
+            code,
+            reason: status.canonical_reason().unwrap_or_default().to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Why a fetch failed, classified so the host app can react to it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FetchError {
+    MissingUrl,
+    InvalidUrl(String),
+    /// A header or the body can't be sent as entered.
+    InvalidRequest(String),
+    Dns(String),
+    Connect(String),
+    Tls(String),
+    Timeout,
+    /// The server answered with a 4xx or 5xx status.
+    Status {
+        code: u16,
+        reason: String,
+    },
+    TooManyRedirects,
+    Body(String),
+    Other(String),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::MissingUrl => write!(f, "Please enter a URL"),
+            FetchError::InvalidUrl(e) => write!(f, "Invalid URL: {e}"),
+            FetchError::InvalidRequest(e) => write!(f, "Invalid request: {e}"),
+            FetchError::Dns(e) => write!(f, "DNS lookup failed: {e}"),
+            FetchError::Connect(e) => write!(f, "Connection failed: {e}"),
+            FetchError::Tls(e) => write!(f, "TLS error: {e}"),
+            FetchError::Timeout => write!(f, "Request timed out"),
+            FetchError::Status { code, reason } => write!(f, "HTTP {code} {reason}"),
+            FetchError::TooManyRedirects => {
+                write!(f, "Stopped after {MAX_REDIRECTS} redirects")
+            }
+            FetchError::Body(e) => write!(f, "Error reading body: {e}"),
+            FetchError::Other(e) => write!(f, "Error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+impl From<reqwest::Error> for FetchError {
+    fn from(error: reqwest::Error) -> Self {
+        // reqwest only exposes coarse categories, the details live in the
+        // chain of underlying errors (hyper, the resolver, rustls, ...)
+        let mut chain = error.to_string();
+        let mut tls = false;
+        let mut dns = false;
+        let mut source = std::error::Error::source(&error);
+        while let Some(cause) = source {
+            chain.push_str(": ");
+            chain.push_str(&cause.to_string());
+            // rustls errors come wrapped in io::Errors, which skip the
+            // wrapped error in `source()`
+            let mut inner = cause;
+            while let Some(wrapped) = inner
+                .downcast_ref::<std::io::Error>()
+                .and_then(|io| io.get_ref())
+            {
+                inner = wrapped;
+            }
+            tls |= inner.is::<rustls::Error>();
+            dns |= inner.is::<timing::ResolveError>();
+            source = cause.source();
+        }
+
+        if error.is_timeout() {
+            FetchError::Timeout
+        } else if error.is_builder() {
+            FetchError::InvalidUrl(chain)
+        } else if error.is_body() || error.is_decode() {
+            FetchError::Body(chain)
+        } else if tls {
+            FetchError::Tls(chain)
+        } else if dns {
+            FetchError::Dns(chain)
+        } else if error.is_connect() {
+            FetchError::Connect(chain)
+        } else {
+            FetchError::Other(chain)
+        }
+    }
+}
+
+/// Fetch a URL with `fetcher`, ignoring progress.
+pub fn fetch_url(
+    fetcher: &dyn Fetcher,
+    request: Request,
+) -> BoxFuture<'static, Result<FetchOutcome, FetchError>> {
+    fetcher.fetch(request, Box::new(|_| {}))
+}
+
+/// Like [`fetch_url`], but reports [`Progress`] while the body downloads.
+///
+/// Progress is dropped rather than waited for when the receiver falls
+/// behind; the final result always arrives.
+pub fn fetch_stream(fetcher: Arc<dyn Fetcher>, request: Request) -> impl Stream<Item = Update> {
+    let (mut output, updates) = mpsc::channel(100);
+    let fetch = async move {
+        let mut sender = output.clone();
+        let progress = Box::new(move |progress| {
+            let _ = sender.try_send(Update::Progress(progress));
+        });
+        let result = fetcher.fetch(request, progress).await;
+        let _ = output.send(Update::Finished(Box::new(result))).await;
+    };
+    // The fetch runs as the stream is polled and yields nothing itself
+    stream::select(fetch.into_stream().filter_map(|()| async { None }), updates)
+}
+
+/// Fetches over the network with reqwest. Redirects are followed by hand so
+/// every hop can be shown.
+#[derive(Debug, Clone, Default)]
+pub struct Network {
+    cache: Cache,
+}
+
+impl Network {
+    /// Keep responses in `cache` instead of a fresh in-memory one, e.g.
+    /// [`Cache::on_disk`].
+    pub fn with_cache(cache: Cache) -> Self {
+        Self { cache }
+    }
+}
+
+impl Fetcher for Network {
+    fn fetch(
+        &self,
+        request: Request,
+        progress: Reporter,
+    ) -> BoxFuture<'static, Result<FetchOutcome, FetchError>> {
+        Box::pin(fetch(self.cache.clone(), request, progress))
+    }
+}
+
+async fn fetch(
+    cache: Cache,
+    request: Request,
+    mut progress: Reporter,
+) -> Result<FetchOutcome, FetchError> {
+    let mut next = request.full_url()?;
+    let mut headers = request.header_map()?;
+    let mut body = request.encoded_body()?;
+    let mut method = request.method;
+    let client = timing::client()?;
+    let start = Instant::now();
+    // The timeout covers the whole fetch, each hop only gets what is left
+    let deadline = start + request.timeout;
+    // The cache is keyed by URL alone, which the headers could make a
+    // different page, e.g. once logged in
+    let use_cache = request.use_cache && headers.is_empty();
+
+    let mut redirects = Vec::new();
+    let (mut response, mut timing) = loop {
+        let cached = if use_cache && method == Method::Get {
+            cache.get(next.as_str()).await
+        } else {
+            None
+        };
+        if let Some(cached) = &cached
+            && cached.is_fresh()
+        {
+            let timing = Timing {
+                redirects: redirects.iter().map(|hop: &Hop| hop.elapsed).sum(),
+                ..Timing::default()
+            };
+            return Ok(cached.outcome(
+                next.to_string(),
+                CacheStatus::Hit,
+                timing,
+                start.elapsed(),
+                redirects,
+            ));
+        }
+
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Err(FetchError::Timeout);
+        }
+        let mut builder = client
+            .request(method.into(), next.clone())
+            .headers(headers.clone())
+            .timeout(remaining);
+        // Validators typed into the request builder win
+        if let Some(cached) = &cached {
+            for (name, value) in &cached.conditional_headers() {
+                if !headers.contains_key(name) {
+                    builder = builder.header(name, value);
+                }
+            }
+        }
+        if let Some((content_type, body)) = &body {
+            if !headers.contains_key(reqwest::header::CONTENT_TYPE) {
+                builder = builder.header(reqwest::header::CONTENT_TYPE, *content_type);
+            }
+            builder = builder.body(body.clone());
+        }
+
+        let sent = Instant::now();
+        let (response, mut timing) = timing::send(builder).await?;
+
+        if response.status() == reqwest::StatusCode::NOT_MODIFIED
+            && let Some(cached) = cached
+        {
+            timing.redirects = redirects.iter().map(|hop: &Hop| hop.elapsed).sum();
+            cache.refresh(cached.clone()).await;
+            return Ok(cached.outcome(
+                next.to_string(),
+                CacheStatus::Revalidated,
+                timing,
+                start.elapsed(),
+                redirects,
+            ));
+        }
+
+        let location = response
+            .headers()
+            .get(reqwest::header::LOCATION)
+            .and_then(|location| location.to_str().ok());
+        let location = match location {
+            Some(location) if request.follow_redirects && response.status().is_redirection() => {
+                location.to_string()
+            }
+            _ => {
+                timing.redirects = redirects.iter().map(|hop: &Hop| hop.elapsed).sum();
+                break (response, timing);
+            }
+        };
+        if redirects.len() == MAX_REDIRECTS {
+            return Err(FetchError::TooManyRedirects);
+        }
+
+        let status = response.status().as_u16();
+        redirects.push(Hop {
+            url: next.to_string(),
+            status,
+            location: location.clone(),
+            elapsed: sent.elapsed(),
+        });
+        if method.after_redirect(status) != method {
+            method = method.after_redirect(status);
+            body = None;
+            for name in BODY_HEADERS {
+                headers.remove(name);
+            }
+        }
+        let target = next
+            .join(&location)
+            .map_err(|e| FetchError::InvalidUrl(format!("{location}: {e}")))?;
+        let previous = std::mem::replace(&mut next, target);
+        // Like browsers, don't hand credentials to another site, reqwest
+        // only does this for redirects it follows itself
+        if next.origin() != previous.origin() {
+            for name in CREDENTIAL_HEADERS {
+                headers.remove(name);
+            }
+        }
+    };
+
+    let status = response.status().as_u16();
+    check_status(status)?;
+
+    let headers: Vec<(String, String)> = response
+        .headers()
+        .iter()
+        .map(|(name, value)| {
+            (
+                name.to_string(),
+                String::from_utf8_lossy(value.as_bytes()).into_owned(),
+            )
+        })
+        .collect();
+
+    let total = response.content_length();
+    let headers_received = Instant::now();
+    let mut reported = headers_received;
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        body.extend_from_slice(&chunk);
+        if reported.elapsed() >= PROGRESS_INTERVAL {
+            reported = Instant::now();
+            progress(Progress {
+                received: body.len(),
+                total,
+                elapsed: headers_received.elapsed(),
+            });
+        }
+    }
+    timing.download = headers_received.elapsed();
+
+    if use_cache && method == Method::Get && status == 200 {
+        cache
+            .store(response.url().as_str(), status, &headers, &body)
+            .await;
+    }
+
+    let mut outcome = FetchOutcome {
+        url: response.url().to_string(),
+        status,
+        headers,
+        elapsed: start.elapsed(),
+        timing,
+        size: 0,
+        metadata: Metadata::default(),
+        redirects,
+        body: String::new(),
+        cache: CacheStatus::Fetched,
+    };
+    outcome.read_body(&body);
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Canned, Fixtures, UrlAnalyzer};
+
+    fn full_url(url: &str) -> Result<String, FetchError> {
+        let request = UrlAnalyzer::default().request(url.to_string());
+        request.full_url().map(String::from)
+    }
+
+    #[test]
+    fn url_without_scheme_gets_one() {
+        assert_eq!(full_url("example.com").unwrap(), "http://example.com/");
+        assert_eq!(
+            full_url("example.com/login?next=https://example.org").unwrap(),
+            "http://example.com/login?next=https://example.org"
+        );
+    }
+
+    #[test]
+    fn only_http_schemes_are_fetched() {
+        assert_eq!(
+            full_url("HTTPS://example.com").unwrap(),
+            "https://example.com/"
+        );
+        assert!(matches!(
+            full_url("ftp://example.com"),
+            Err(FetchError::InvalidUrl(_))
+        ));
+        assert!(matches!(
+            full_url("svn+ssh://example.com"),
+            Err(FetchError::InvalidUrl(_))
+        ));
+    }
+
+    /// What sending `url` with the shared client fails with.
+    fn send_error(url: &str) -> FetchError {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let client = timing::client().unwrap();
+        runtime.block_on(client.get(url).send()).unwrap_err().into()
+    }
+
+    #[test]
+    fn errors_are_classified_by_cause_not_by_url() {
+        // Nothing listens on port 1
+        let refused = send_error("http://127.0.0.1:1/tls/dns");
+        assert!(matches!(refused, FetchError::Connect(_)), "{refused}");
+
+        let unresolved = send_error("http://tls.invalid/");
+        assert!(matches!(unresolved, FetchError::Dns(_)), "{unresolved}");
+
+        // A plain HTTP server doesn't speak TLS
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        std::thread::spawn(move || {
+            use std::io::{Read, Write};
+            let (mut stream, _) = listener.accept().unwrap();
+            let _ = stream.read(&mut [0; 1024]);
+            let _ = stream.write_all(b"HTTP/1.1 400 Bad Request\r\n\r\n");
+        });
+        let plain = send_error(&format!("https://127.0.0.1:{port}/dns"));
+        assert!(matches!(plain, FetchError::Tls(_)), "{plain}");
+    }
+
+    #[test]
+    fn timeout_covers_every_redirect() {
+        // Each response takes 200ms and redirects back to the server
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        std::thread::spawn(move || {
+            use std::io::{Read, Write};
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { break };
+                std::thread::spawn(move || {
+                    while stream.read(&mut [0; 1024]).is_ok_and(|n| n > 0) {
+                        std::thread::sleep(Duration::from_millis(200));
+                        let response = "HTTP/1.1 302 Found\r\nLocation: /again\r\n\
+                                        Content-Length: 0\r\n\r\n";
+                        if stream.write_all(response.as_bytes()).is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+        });
+
+        let mut request = UrlAnalyzer::default().request(format!("127.0.0.1:{port}"));
+        request.timeout = Duration::from_secs(1);
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let start = Instant::now();
+        let result = runtime.block_on(fetch_url(&Network::default(), request));
+
+        assert!(matches!(result, Err(FetchError::Timeout)), "{result:?}");
+        assert!(start.elapsed() < Duration::from_secs(2));
+    }
+
+    #[test]
+    fn stream_reports_progress_then_the_result() {
+        let fixtures = Fixtures::default().with("http://site.test/", Canned::html("<p>Hi</p>"));
+        let request = UrlAnalyzer::default().request("site.test".to_string());
+
+        let updates: Vec<Update> =
+            futures::executor::block_on(fetch_stream(Arc::new(fixtures), request).collect());
+        assert!(matches!(
+            updates.as_slice(),
+            [Update::Progress(_), Update::Finished(result)] if result.is_ok()
+        ));
+    }
+
+    #[test]
+    fn scheme_is_split_off_only_at_the_start() {
+        assert_eq!(
+            split_scheme("https://example.com"),
+            Some((true, "example.com"))
+        );
+        assert_eq!(split_scheme("example.com/?next=http://example.org"), None);
+    }
+}
@@ -0,0 +1,288 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::future::BoxFuture;
+
+use crate::fetch::{self, Fetcher, Hop, MAX_REDIRECTS, Reporter, Request};
+use crate::{CacheStatus, FetchError, FetchOutcome, Metadata, Progress, Timing};
+
+/// A response served by [`Fixtures`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Canned {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl Canned {
+    /// `200 OK` with an HTML body.
+    pub fn html(body: impl Into<String>) -> Self {
+        Self {
+            status: 200,
+            headers: vec![("content-type".into(), "text/html; charset=utf-8".into())],
+            body: body.into().into_bytes(),
+        }
+    }
+
+    /// `302 Found` pointing at `location`.
+    pub fn redirect(location: impl Into<String>) -> Self {
+        Self {
+            status: 302,
+            headers: vec![("location".into(), location.into())],
+            body: Vec::new(),
+        }
+    }
+
+    /// An empty response with the given status, e.g. `503`.
+    pub fn status(status: u16) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: Vec::new(),
+        }
+    }
+}
+
+/// Answers from canned responses instead of the network, for demos without
+/// Wi-Fi and for tests that must not depend on it.
+///
+/// URLs are looked up first among the responses added with
+/// [`Fixtures::with`], then as files in the fixture directory, laid out the
+/// way `wget --recursive` saves a site: `https://example.com/docs/` is served
+/// from `<dir>/example.com/docs/index.html`. A host without a directory
+/// fails like a DNS lookup, a missing file is a 404.
+///
+/// Nothing is timed, so every duration is zero and results are the same on
+/// every run.
+#[derive(Debug, Clone, Default)]
+pub struct Fixtures {
+    dir: Option<PathBuf>,
+    canned: Arc<HashMap<String, Canned>>,
+}
+
+impl Fixtures {
+    /// Serve the files in `dir`.
+    pub fn dir(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: Some(dir.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Answer `url` with `response`, ahead of the fixture directory.
+    ///
+    /// # Panics
+    ///
+    /// If `url` isn't an absolute URL.
+    pub fn with(mut self, url: &str, response: Canned) -> Self {
+        let url = reqwest::Url::parse(url).expect("fixture URLs must be absolute");
+        Arc::make_mut(&mut self.canned).insert(url.to_string(), response);
+        self
+    }
+
+    fn respond(&self, url: &reqwest::Url) -> Result<Canned, FetchError> {
+        if let Some(canned) = self.canned.get(url.as_str()) {
+            return Ok(canned.clone());
+        }
+
+        let host = url.host_str().unwrap_or_default();
+        let host = match url.port() {
+            Some(port) => format!("{host}:{port}"),
+            None => host.to_string(),
+        };
+        let site = match &self.dir {
+            Some(dir) if dir.join(&host).is_dir() => dir.join(&host),
+            _ => return Err(FetchError::Dns(format!("no fixtures for {host}"))),
+        };
+
+        let mut path = site.join(url.path().trim_start_matches('/'));
+        if path.is_dir() {
+            path.push("index.html");
+        }
+        match std::fs::read(&path) {
+            Ok(body) => Ok(Canned {
+                status: 200,
+                headers: vec![("content-type".into(), content_type(&path).into())],
+                body,
+            }),
+            Err(_) => Ok(Canned::status(404)),
+        }
+    }
+}
+
+impl Fetcher for Fixtures {
+    fn fetch(
+        &self,
+        request: Request,
+        mut progress: Reporter,
+    ) -> BoxFuture<'static, Result<FetchOutcome, FetchError>> {
+        let fixtures = self.clone();
+        Box::pin(async move {
+            // Methods, headers and bodies don't change the canned answers,
+            // but a bad request still fails like it would online
+            let mut next = request.full_url()?;
+            request.header_map()?;
+            request.encoded_body()?;
+            let mut redirects = Vec::new();
+            let response = loop {
+                let response = fixtures.respond(&next)?;
+                let location = match fetch::header(&response.headers, "location") {
+                    Some(location)
+                        if request.follow_redirects && (300..400).contains(&response.status) =>
+                    {
+                        location.to_string()
+                    }
+                    _ => break response,
+                };
+                if redirects.len() == MAX_REDIRECTS {
+                    return Err(FetchError::TooManyRedirects);
+                }
+
+                redirects.push(Hop {
+                    url: next.to_string(),
+                    status: response.status,
+                    location: location.clone(),
+                    elapsed: Duration::ZERO,
+                });
+                next = next
+                    .join(&location)
+                    .map_err(|e| FetchError::InvalidUrl(format!("{location}: {e}")))?;
+            };
+
+            fetch::check_status(response.status)?;
+            progress(Progress {
+                received: response.body.len(),
+                total: Some(response.body.len() as u64),
+                elapsed: Duration::ZERO,
+            });
+
+            let mut outcome = FetchOutcome {
+                url: next.to_string(),
+                status: response.status,
+                headers: response.headers,
+                elapsed: Duration::ZERO,
+                timing: Timing::default(),
+                size: 0,
+                metadata: Metadata::default(),
+                redirects,
+                body: String::new(),
+                cache: CacheStatus::Fetched,
+            };
+            outcome.read_body(&response.body);
+            Ok(outcome)
+        })
+    }
+}
+
+/// Guessed from the extension, like a static file server would.
+fn content_type(path: &Path) -> &'static str {
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("html" | "htm") => "text/html; charset=utf-8",
+        Some("css") => "text/css",
+        Some("js") => "text/javascript",
+        Some("json") => "application/json",
+        Some("txt") => "text/plain; charset=utf-8",
+        Some("svg") => "image/svg+xml",
+        Some("png") => "image/png",
+        Some("jpg" | "jpeg") => "image/jpeg",
+        _ => "application/octet-stream",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UrlAnalyzer;
+
+    fn get(fixtures: &Fixtures, url: &str) -> Result<FetchOutcome, FetchError> {
+        get_with(fixtures, UrlAnalyzer::default().request(url.to_string()))
+    }
+
+    fn get_with(fixtures: &Fixtures, request: Request) -> Result<FetchOutcome, FetchError> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        runtime.block_on(fixtures.fetch(request, Box::new(|_| {})))
+    }
+
+    #[test]
+    fn follows_a_redirect_chain() {
+        let fixtures = Fixtures::default()
+            .with("http://a.test/", Canned::redirect("https://b.test/start"))
+            .with("https://b.test/start", Canned::redirect("/end"))
+            .with("https://b.test/end", Canned::html("<title>End</title>"));
+
+        let outcome = get(&fixtures, "a.test").unwrap();
+        assert_eq!(outcome.url, "https://b.test/end");
+        assert_eq!(outcome.status, 200);
+        assert_eq!(outcome.metadata.title.as_deref(), Some("End"));
+        let hops: Vec<_> = outcome
+            .redirects
+            .iter()
+            .map(|hop| (hop.url.as_str(), hop.status, hop.location.as_str()))
+            .collect();
+        assert_eq!(
+            hops,
+            [
+                ("http://a.test/", 302, "https://b.test/start"),
+                ("https://b.test/start", 302, "/end"),
+            ]
+        );
+    }
+
+    #[test]
+    fn gives_up_on_a_redirect_loop() {
+        let fixtures = Fixtures::default()
+            .with("http://loop.test/a", Canned::redirect("/b"))
+            .with("http://loop.test/b", Canned::redirect("/a"));
+
+        assert_eq!(
+            get(&fixtures, "loop.test/a"),
+            Err(FetchError::TooManyRedirects)
+        );
+    }
+
+    #[test]
+    fn error_statuses_fail() {
+        let fixtures = Fixtures::default()
+            .with("http://site.test/missing", Canned::status(404))
+            .with("http://site.test/broken", Canned::status(500));
+
+        assert!(matches!(
+            get(&fixtures, "site.test/missing"),
+            Err(FetchError::Status { code: 404, .. })
+        ));
+        assert!(matches!(
+            get(&fixtures, "site.test/broken"),
+            Err(FetchError::Status { code: 500, .. })
+        ));
+    }
+
+    #[test]
+    fn unknown_host_fails_like_dns() {
+        let fixtures = Fixtures::default().with("http://site.test/", Canned::html(""));
+
+        assert!(matches!(
+            get(&fixtures, "elsewhere.test"),
+            Err(FetchError::Dns(_))
+        ));
+    }
+
+    #[test]
+    fn redirect_is_final_when_not_following() {
+        let fixtures = Fixtures::default()
+            .with("http://site.test/old", Canned::redirect("/new"))
+            .with("http://site.test/new", Canned::html(""));
+        let request = Request {
+            follow_redirects: false,
+            ..UrlAnalyzer::default().request("site.test/old".to_string())
+        };
+
+        let outcome = get_with(&fixtures, request).unwrap();
+        assert_eq!(outcome.url, "http://site.test/old");
+        assert_eq!(outcome.status, 302);
+        assert!(outcome.redirects.is_empty());
+    }
+}
@@ -0,0 +1,838 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use iced::{
+    Color, Element, Font, Length, Task,
+    widget::{
+        button, canvas, checkbox, column, container, pick_list, progress_bar, row, scrollable,
+        space, text, text_editor, text_input,
+    },
+};
+use iced::{highlighter, task};
+use strum::IntoEnumIterator;
+
+use crate::core::{self, Effect, Mode, Timeout, UrlAnalyzer, describe, summary};
+use crate::{
+    AssetKind, BodyKind, Column, Entry, FetchError, FetchOutcome, Fetcher, History, Method,
+    Network, Progress, Request, Update, fetch_stream, fetch_url, parse_urls,
+};
+use debugger::LogEntry;
+use waterfall::Waterfall;
+
+mod batch;
+mod builder;
+mod crawl;
+mod debugger;
+mod history;
+mod waterfall;
+
+/// Mono font embedded from the shared fonts directory.
+pub const FIRA_MONO_BYTES: &[u8] = include_bytes!("../../fonts/FiraMono-Regular.ttf");
+
+const FIRA_MONO: Font = Font::with_name("Fira Mono");
+
+/// Messages kept in the log before the oldest ones are dropped.
+const MESSAGE_LOG_CAPACITY: usize = 100;
+
+/// Injectable style configuration so the host app can match its own theme.
+pub struct StyleConfig {
+    pub mono_font: Font,
+    pub subtitle_color: Color,
+    pub error_color: Color,
+    /// Highlights the message being looked at when time traveling.
+    pub accent_color: Color,
+    /// Colors request and response bodies.
+    pub highlight_theme: highlighter::Theme,
+    pub text_size: u32,
+}
+
+impl Default for StyleConfig {
+    fn default() -> Self {
+        Self {
+            mono_font: FIRA_MONO,
+            subtitle_color: Color::from_rgb(0.45, 0.45, 0.45),
+            error_color: Color::from_rgb(0.85, 0.25, 0.25),
+            accent_color: Color::from_rgb(1.0, 0.4, 0.0),
+            highlight_theme: highlighter::Theme::InspiredGitHub,
+            text_size: 22,
+        }
+    }
+}
+
+/// Self-contained "Page Poker" component state: the iced view on top of a
+/// [`core::UrlAnalyzer`], plus what only a UI needs, like the message log
+/// and fetches that can be cancelled.
+pub struct PagePoker {
+    model: UrlAnalyzer,
+    message_log: Vec<LogEntry>,
+    /// Messages logged so far, including ones dropped from the log.
+    logged: usize,
+    /// Index of the log entry whose snapshot is shown, when time traveling.
+    scrub: Option<usize>,
+    /// The present model, set aside while a snapshot is shown.
+    live: Option<UrlAnalyzer>,
+    style: StyleConfig,
+    fetcher: Arc<dyn Fetcher>,
+    /// The fetch in flight; dropping it aborts the request.
+    request: Option<task::Handle>,
+    /// What that fetch asked for, for its history entry.
+    requested: Option<(Method, String, bool)>,
+    /// Body download progress of the fetch in flight.
+    progress: Option<Progress>,
+    /// Which tables of the Redirects & Headers mode are expanded.
+    show_redirects: bool,
+    show_headers: bool,
+    show_request: bool,
+    /// Request body editor, mirrored into `model.body` on every edit.
+    request_body: text_editor::Content,
+    /// Read-only view of the response body.
+    response_body: text_editor::Content,
+    /// Batch mode URL list, mirrored into `model.urls` on every edit.
+    batch_input: text_editor::Content,
+    batch_file: String,
+    batch_file_error: Option<String>,
+    /// Batch fetches in flight by row, and the rows still waiting for a slot.
+    batch_requests: HashMap<usize, task::Handle>,
+    batch_queue: VecDeque<(usize, Request)>,
+    /// Same for the assets of the Links & Assets mode.
+    asset_requests: HashMap<usize, task::Handle>,
+    asset_queue: VecDeque<(usize, Request)>,
+    collapsed_assets: HashSet<AssetKind>,
+    history: History,
+    history_export: Option<Result<PathBuf, String>>,
+}
+
+impl PagePoker {
+    /// Create a new PagePoker with default styling.
+    pub fn new() -> Self {
+        Self::with_style(StyleConfig::default())
+    }
+
+    /// Create a new PagePoker with custom styling.
+    pub fn with_style(style: StyleConfig) -> Self {
+        Self::with_fetcher(style, Network::default())
+    }
+
+    /// Create a new PagePoker that gets its pages from `fetcher`, e.g.
+    /// [`Fixtures`] to demo it offline.
+    pub fn with_fetcher(style: StyleConfig, fetcher: impl Fetcher + 'static) -> Self {
+        Self {
+            model: UrlAnalyzer::default(),
+            message_log: Vec::new(),
+            logged: 0,
+            scrub: None,
+            live: None,
+            style,
+            fetcher: Arc::new(fetcher),
+            request: None,
+            requested: None,
+            progress: None,
+            show_redirects: true,
+            show_headers: true,
+            show_request: false,
+            request_body: text_editor::Content::new(),
+            response_body: text_editor::Content::new(),
+            batch_input: text_editor::Content::new(),
+            batch_file: String::new(),
+            batch_file_error: None,
+            batch_requests: HashMap::new(),
+            batch_queue: VecDeque::new(),
+            asset_requests: HashMap::new(),
+            asset_queue: VecDeque::new(),
+            collapsed_assets: HashSet::new(),
+            history: History::default(),
+            history_export: None,
+        }
+    }
+
+    /// Current model, e.g. for persisting it across restarts.
+    pub fn state(&self) -> &UrlAnalyzer {
+        self.live.as_ref().unwrap_or(&self.model)
+    }
+
+    /// Replace the model with a previously saved one.
+    pub fn restore(&mut self, state: UrlAnalyzer) {
+        self.batch_input = text_editor::Content::with_text(&state.urls.join("\n"));
+        self.request_body = text_editor::Content::with_text(&state.body);
+        self.model = state;
+        self.show_response_body();
+    }
+
+    fn show_response_body(&mut self) {
+        let body = match self.model.result.as_deref() {
+            Some(Ok(outcome)) => outcome.body.as_str(),
+            _ => "",
+        };
+        self.response_body = text_editor::Content::with_text(body);
+    }
+
+    /// Start fetching the next queued batch row, if any.
+    fn next_in_batch(&mut self) -> Task<Message> {
+        let Some((index, request)) = self.batch_queue.pop_front() else {
+            return Task::none();
+        };
+        let (task, handle) = Task::future(fetch_url(self.fetcher.as_ref(), request))
+            .map(move |result| Message::BatchResult(index, Box::new(result)))
+            .abortable();
+        self.batch_requests.insert(index, handle.abort_on_drop());
+        task
+    }
+
+    /// Start fetching the next queued asset, if any.
+    fn next_asset(&mut self) -> Task<Message> {
+        let Some((index, request)) = self.asset_queue.pop_front() else {
+            return Task::none();
+        };
+        let (task, handle) = Task::future(fetch_url(self.fetcher.as_ref(), request))
+            .map(move |result| Message::AssetResult(index, Box::new(result)))
+            .abortable();
+        self.asset_requests.insert(index, handle.abort_on_drop());
+        task
+    }
+
+    /// Start what the model asked for when handling `message`.
+    fn perform(&mut self, message: core::Message) -> Task<Message> {
+        match self.model.update(message) {
+            Effect::None => Task::none(),
+            Effect::Fetch(request) => {
+                self.asset_requests.clear();
+                self.asset_queue.clear();
+                self.progress = None;
+                self.requested =
+                    Some((self.model.method, self.model.url.clone(), self.model.secure));
+                let stream = fetch_stream(self.fetcher.clone(), request);
+                let (task, handle) = Task::run(stream, |update| match update {
+                    Update::Progress(progress) => Message::Progress(progress),
+                    Update::Finished(result) => Message::Result(result),
+                })
+                .abortable();
+                self.request = Some(handle.abort_on_drop());
+                task
+            }
+            Effect::FetchRows(requests) => {
+                self.batch_queue = requests.into();
+                // Fill every slot now, each finished fetch then starts the next
+                Task::batch((0..batch::CONCURRENCY).map(|_| self.next_in_batch()))
+            }
+            Effect::FetchAssets(requests) => {
+                self.asset_queue.extend(requests);
+                let free = batch::CONCURRENCY.saturating_sub(self.asset_requests.len());
+                Task::batch((0..free).map(|_| self.next_asset()))
+            }
+        }
+    }
+
+    fn log_message(&mut self, msg: String) {
+        if self.message_log.len() == MESSAGE_LOG_CAPACITY {
+            self.message_log.remove(0);
+            self.scrub = self.scrub.map(|index| index.saturating_sub(1));
+        }
+        self.message_log.push(LogEntry {
+            message: msg,
+            model: self.model.clone(),
+        });
+        self.logged += 1;
+    }
+
+    /// Process a message and return a task.
+    pub fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::Scrub(index) => self.scrub(index),
+            Message::Live => self.go_live(),
+            Message::ReplayFromHere => self.replay_from_here(),
+            message => match self.live.take() {
+                None => self.record(message),
+                // Looking at the past: input is ignored, but results of
+                // requests made in the present still land there
+                Some(live) if message.is_background() => {
+                    let shown = std::mem::replace(&mut self.model, live);
+                    let task = self.record(message);
+                    self.live = Some(std::mem::replace(&mut self.model, shown));
+                    task
+                }
+                Some(live) => {
+                    self.live = Some(live);
+                    Task::none()
+                }
+            },
+        }
+    }
+
+    fn apply(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::MethodChanged(method) => {
+                self.log_message(format!("MethodChanged({method})"));
+                self.perform(core::Message::MethodChanged(method))
+            }
+            Message::UrlChanged(url) => {
+                self.log_message(format!("UrlChanged({:?})", url));
+                self.perform(core::Message::UrlChanged(url))
+            }
+            Message::SecureChanged(secure) => {
+                self.log_message(format!("SecureChanged({})", secure));
+                self.perform(core::Message::SecureChanged(secure))
+            }
+            Message::ModeChanged(mode) => {
+                self.log_message(format!("ModeChanged({})", mode));
+                self.perform(core::Message::ModeChanged(mode))
+            }
+            Message::TimeoutChanged(timeout) => {
+                self.log_message(format!("TimeoutChanged({timeout})"));
+                self.perform(core::Message::TimeoutChanged(timeout))
+            }
+            Message::FollowRedirectsChanged(follow) => {
+                self.log_message(format!("FollowRedirectsChanged({follow})"));
+                self.perform(core::Message::FollowRedirectsChanged(follow))
+            }
+            Message::UseCacheChanged(use_cache) => {
+                self.log_message(format!("UseCacheChanged({use_cache})"));
+                self.perform(core::Message::UseCacheChanged(use_cache))
+            }
+            Message::ToggleRedirects => {
+                self.log_message("ToggleRedirects".to_string());
+                self.show_redirects = !self.show_redirects;
+                Task::none()
+            }
+            Message::ToggleHeaders => {
+                self.log_message("ToggleHeaders".to_string());
+                self.show_headers = !self.show_headers;
+                Task::none()
+            }
+            Message::ToggleRequest => {
+                self.log_message("ToggleRequest".to_string());
+                self.show_request = !self.show_request;
+                Task::none()
+            }
+            Message::AddHeader => {
+                self.log_message("AddHeader".to_string());
+                self.perform(core::Message::AddHeader)
+            }
+            Message::RemoveHeader(index) => {
+                self.log_message(format!("RemoveHeader({index})"));
+                self.perform(core::Message::RemoveHeader(index))
+            }
+            Message::HeaderNameChanged(index, name) => {
+                self.log_message(format!("HeaderNameChanged({index}, {name:?})"));
+                self.perform(core::Message::HeaderNameChanged(index, name))
+            }
+            Message::HeaderValueChanged(index, value) => {
+                self.log_message(format!("HeaderValueChanged({index}, {value:?})"));
+                self.perform(core::Message::HeaderValueChanged(index, value))
+            }
+            Message::BodyKindChanged(kind) => {
+                self.log_message(format!("BodyKindChanged({kind})"));
+                self.perform(core::Message::BodyKindChanged(kind))
+            }
+            Message::BodyEdited(action) => {
+                let is_edit = action.is_edit();
+                self.request_body.perform(action);
+                if !is_edit {
+                    return Task::none();
+                }
+                let body = self.request_body.text();
+                self.log_message(format!("BodyEdited({} bytes)", body.len()));
+                self.perform(core::Message::BodyChanged(body))
+            }
+            // Selecting and scrolling only, the body can't be edited
+            Message::ResponseBodyAction(action) => {
+                if !action.is_edit() {
+                    self.response_body.perform(action);
+                }
+                Task::none()
+            }
+            Message::BatchChanged(batch) => {
+                self.log_message(format!("BatchChanged({batch})"));
+                self.perform(core::Message::BatchChanged(batch))
+            }
+            Message::BatchEdited(action) => {
+                let is_edit = action.is_edit();
+                self.batch_input.perform(action);
+                if !is_edit {
+                    return Task::none();
+                }
+                let urls = parse_urls(&self.batch_input.text());
+                self.log_message(format!("BatchEdited({} URLs)", urls.len()));
+                self.perform(core::Message::UrlsChanged(urls))
+            }
+            Message::BatchFileChanged(path) => {
+                self.log_message(format!("BatchFileChanged({path:?})"));
+                self.batch_file = path;
+                Task::none()
+            }
+            Message::LoadBatchFile => {
+                self.log_message("LoadBatchFile".to_string());
+                let path = self.batch_file.clone();
+                Task::perform(
+                    async move { std::fs::read_to_string(&path).map_err(|e| format!("{path}: {e}")) },
+                    Message::BatchFileLoaded,
+                )
+            }
+            Message::BatchFileLoaded(Ok(list)) => {
+                let urls = parse_urls(&list);
+                self.log_message(format!("BatchFileLoaded({} URLs)", urls.len()));
+                self.batch_input = text_editor::Content::with_text(&urls.join("\n"));
+                self.batch_file_error = None;
+                self.perform(core::Message::UrlsChanged(urls))
+            }
+            Message::BatchFileLoaded(Err(error)) => {
+                self.log_message(format!("BatchFileLoaded(Err({error:?}))"));
+                self.batch_file_error = Some(error);
+                Task::none()
+            }
+            Message::SortBy(column) => {
+                self.log_message(format!("SortBy({column:?})"));
+                self.perform(core::Message::SortBy(column))
+            }
+            Message::Action => {
+                if self.model.batch {
+                    self.log_message(format!("Action({} URLs)", self.model.urls.len()));
+                } else {
+                    self.log_message("Action".to_string());
+                }
+                self.perform(core::Message::Fetch)
+            }
+            Message::Cancelled => {
+                self.log_message("Cancelled".to_string());
+                self.request = None;
+                self.requested = None;
+                self.progress = None;
+                self.batch_requests.clear();
+                self.batch_queue.clear();
+                self.asset_requests.clear();
+                self.asset_queue.clear();
+                self.perform(core::Message::Cancelled)
+            }
+            Message::BatchResult(index, result) => {
+                let summary = FetchOutcome::log_summary(&result);
+                self.log_message(format!("BatchResult({index}, {summary})"));
+                self.batch_requests.remove(&index);
+                if let Some(row) = self.model.rows.get(index) {
+                    self.history.push(Entry::new(
+                        self.model.method,
+                        row.url.clone(),
+                        self.model.secure,
+                        self.model.mode,
+                        &result,
+                    ));
+                }
+                Task::batch([
+                    self.perform(core::Message::RowFetched(index, result)),
+                    self.next_in_batch(),
+                ])
+            }
+            // Arrives many times a second, so it is left out of the log
+            Message::Progress(progress) => {
+                self.progress = Some(progress);
+                Task::none()
+            }
+            Message::Result(result) => {
+                let summary = FetchOutcome::log_summary(&result);
+                self.log_message(format!("Result({summary})"));
+                self.request = None;
+                self.progress = None;
+                if let Some((method, url, secure)) = self.requested.take() {
+                    self.history
+                        .push(Entry::new(method, url, secure, self.model.mode, &result));
+                }
+                let task = self.perform(core::Message::Fetched(result));
+                self.show_response_body();
+                task
+            }
+            Message::FetchAssets => {
+                self.log_message(format!("FetchAssets({} assets)", self.model.assets.len()));
+                self.perform(core::Message::FetchAssets)
+            }
+            Message::AssetResult(index, result) => {
+                let summary = FetchOutcome::log_summary(&result);
+                self.log_message(format!("AssetResult({index}, {summary})"));
+                self.asset_requests.remove(&index);
+                // The freed slot goes to the queue first, a GET retrying a
+                // refused HEAD waits its turn
+                let next = self.next_asset();
+                Task::batch([
+                    next,
+                    self.perform(core::Message::AssetFetched(index, result)),
+                ])
+            }
+            Message::ToggleAssets(kind) => {
+                self.log_message(format!("ToggleAssets({kind:?})"));
+                if !self.collapsed_assets.remove(&kind) {
+                    self.collapsed_assets.insert(kind);
+                }
+                Task::none()
+            }
+            Message::Replay(index) => {
+                self.log_message(format!("Replay({index})"));
+                let Some(entry) = self.history.get(index).cloned() else {
+                    return Task::none();
+                };
+                if self.model.loading {
+                    return Task::none();
+                }
+                let changes = [
+                    core::Message::MethodChanged(entry.method),
+                    core::Message::UrlChanged(entry.url),
+                    core::Message::SecureChanged(entry.secure),
+                    core::Message::ModeChanged(entry.mode),
+                    core::Message::BatchChanged(false),
+                ];
+                for change in changes {
+                    let _ = self.model.update(change);
+                }
+                self.update(Message::Action)
+            }
+            Message::ExportHistory => {
+                self.log_message("ExportHistory".to_string());
+                Task::perform(
+                    self.history.clone().save(PathBuf::from(History::FILE)),
+                    Message::HistoryExported,
+                )
+            }
+            Message::HistoryExported(result) => {
+                self.log_message(format!("HistoryExported({result:?})"));
+                self.history_export = Some(result);
+                Task::none()
+            }
+            // Handled by `update` before getting here
+            Message::Scrub(_) | Message::Live | Message::ReplayFromHere => Task::none(),
+        }
+    }
+
+    /// Render the Page Poker UI.
+    pub fn view(&self) -> Element<'_, Message> {
+        let mono = self.style.mono_font;
+        let subtitle = self.style.subtitle_color;
+        let ts = self.style.text_size;
+
+        let mode_options: Vec<Mode> = Mode::iter().collect();
+
+        let get_button = if self.model.loading {
+            button("Cancel").on_press(Message::Cancelled)
+        } else {
+            button("Get").on_press_maybe(self.model.can_fetch().then_some(Message::Action))
+        };
+
+        let result: Element<'_, Message> = match self.model.result.as_deref() {
+            _ if self.model.batch => self.view_batch(),
+            // Assets being fetched are shown as they come in
+            _ if self.model.loading && self.asset_requests.is_empty() => self.view_progress(),
+            None => text("Enter a URL and click Get").size(ts).into(),
+            Some(Ok(outcome)) if self.model.mode == Mode::Headers => column![
+                self.view_inspector(outcome),
+                text(summary(outcome)).size(14).font(mono).color(subtitle),
+            ]
+            .spacing(8)
+            .into(),
+            Some(Ok(outcome)) if self.model.mode == Mode::Crawl => column![
+                self.view_assets(outcome.size),
+                text(summary(outcome)).size(14).font(mono).color(subtitle),
+            ]
+            .spacing(8)
+            .into(),
+            Some(Ok(outcome)) if self.model.mode == Mode::Body => column![
+                self.view_response_body(outcome),
+                text(summary(outcome)).size(14).font(mono).color(subtitle),
+            ]
+            .spacing(8)
+            .into(),
+            Some(Ok(outcome)) if self.model.mode == Mode::DownloadTime => {
+                let waterfall = Waterfall::new(&outcome.timing, mono, subtitle);
+                let height = waterfall.height();
+                column![
+                    text(describe(outcome, self.model.mode)).size(ts),
+                    canvas(waterfall).width(Length::Fill).height(height),
+                    text(summary(outcome)).size(14).font(mono).color(subtitle),
+                ]
+                .spacing(8)
+                .into()
+            }
+            Some(Ok(outcome)) => column![
+                text(describe(outcome, self.model.mode)).size(ts),
+                text(summary(outcome)).size(14).font(mono).color(subtitle),
+            ]
+            .spacing(8)
+            .into(),
+            Some(Err(error)) => text(error.to_string())
+                .size(ts)
+                .color(self.style.error_color)
+                .into(),
+        };
+
+        // RON state visualization
+        let ron_config = ron::ser::PrettyConfig::default();
+        let state_ron = ron::ser::to_string_pretty(&self.model, ron_config)
+            .unwrap_or_else(|e| format!("Error: {e}"));
+
+        let (url_input, batch_input): (Element<'_, Message>, Element<'_, Message>) =
+            if self.model.batch {
+                (space().width(Length::Fill).into(), self.view_batch_input())
+            } else {
+                (
+                    text_input("Enter URL (e.g. example.com)", &self.model.url)
+                        .on_input(Message::UrlChanged)
+                        .on_submit(Message::Action)
+                        .into(),
+                    space().into(),
+                )
+            };
+
+        let url_error: Element<'_, Message> = match self.model.url_error() {
+            Some(error) if !self.model.batch => container(
+                text(error.to_string())
+                    .size(14)
+                    .color(self.style.error_color),
+            )
+            .padding(iced::Padding::ZERO.top(4))
+            .into(),
+            _ => space().into(),
+        };
+
+        column![
+            // Input row
+            row![
+                pick_list(
+                    Method::iter().collect::<Vec<_>>(),
+                    Some(self.model.method),
+                    Message::MethodChanged
+                ),
+                url_input,
+                pick_list(mode_options, Some(self.model.mode), Message::ModeChanged),
+                get_button,
+            ]
+            .spacing(12)
+            .align_y(iced::Alignment::Center),
+            url_error,
+            space().height(12),
+            // Options row
+            row![
+                checkbox(self.model.batch)
+                    .label("Batch")
+                    .on_toggle(Message::BatchChanged),
+                checkbox(self.model.secure)
+                    .label("HTTPS")
+                    .on_toggle(Message::SecureChanged),
+                checkbox(self.model.follow_redirects)
+                    .label("Follow redirects")
+                    .on_toggle(Message::FollowRedirectsChanged),
+                checkbox(self.model.use_cache)
+                    .label("Cache")
+                    .on_toggle(Message::UseCacheChanged),
+                text("Timeout"),
+                pick_list(
+                    Timeout::ALL,
+                    Some(self.model.timeout),
+                    Message::TimeoutChanged
+                ),
+            ]
+            .spacing(12)
+            .align_y(iced::Alignment::Center),
+            self.view_request_builder(),
+            batch_input,
+            space().height(24),
+            // Result
+            result,
+            space().height(36),
+            // State and messages side by side
+            row![
+                column![
+                    text("Current State").size(16).font(mono).color(subtitle),
+                    space().height(8),
+                    container(scrollable(text(state_ron).size(14).font(mono)))
+                        .width(iced::Fill)
+                        .padding(12)
+                        .style(container::rounded_box),
+                ]
+                .height(iced::Fill)
+                .width(Length::FillPortion(1)),
+                column![
+                    text(match self.scrub {
+                        Some(index) => {
+                            format!("Message {} of {}", index + 1, self.message_log.len())
+                        }
+                        None => "Recent Messages".to_string(),
+                    })
+                    .size(16)
+                    .font(mono)
+                    .color(subtitle),
+                    space().height(8),
+                    container(self.view_message_log())
+                        .height(174)
+                        .width(iced::Fill)
+                        .padding(12)
+                        .style(container::rounded_box),
+                ]
+                .height(iced::Fill)
+                .width(Length::FillPortion(1)),
+                column![
+                    row![
+                        text("History").size(16).font(mono).color(subtitle),
+                        space().width(Length::Fill),
+                        button(text("Export JSON").size(14))
+                            .padding([2, 8])
+                            .on_press(Message::ExportHistory),
+                    ]
+                    .align_y(iced::Alignment::Center)
+                    .height(20),
+                    space().height(8),
+                    container(scrollable(self.view_history()).height(150))
+                        .width(iced::Fill)
+                        .padding(12)
+                        .style(container::rounded_box),
+                ]
+                .height(iced::Fill)
+                .width(Length::FillPortion(1)),
+            ]
+            .spacing(20),
+        ]
+        .width(iced::Fill)
+        .into()
+    }
+
+    /// Download progress of the fetch in flight.
+    fn view_progress(&self) -> Element<'_, Message> {
+        let ts = self.style.text_size;
+        let Some(progress) = self.progress else {
+            return text("Fetching...").size(ts).into();
+        };
+
+        let size = |bytes: f64| humansize::format_size(bytes as u64, humansize::DECIMAL);
+        let received = size(progress.received as f64);
+        let rate = size(progress.rate());
+
+        let mut content = column![text("Downloading...").size(ts)].spacing(8);
+        let label = match progress.total {
+            Some(total) => {
+                content = content
+                    .push(progress_bar(0.0..=total as f32, progress.received as f32).girth(8));
+                format!("{received} of {} · {rate}/s", size(total as f64))
+            }
+            // Without a Content-Length there is nothing to fill
+            None => format!("{received} · {rate}/s"),
+        };
+
+        content
+            .push(
+                text(label)
+                    .size(14)
+                    .font(self.style.mono_font)
+                    .color(self.style.subtitle_color),
+            )
+            .into()
+    }
+
+    /// Collapsible tables of the redirect chain and the final headers.
+    fn view_inspector<'a>(&self, outcome: &'a FetchOutcome) -> Element<'a, Message> {
+        let mono = self.style.mono_font;
+        let subtitle = self.style.subtitle_color;
+
+        let toggle = |label: String, expanded: bool, message: Message| {
+            let arrow = if expanded { "▾" } else { "▸" };
+            button(text(format!("{arrow} {label}")).size(16).font(mono))
+                .style(button::text)
+                .padding(0)
+                .on_press(message)
+        };
+
+        let mut sections = column![].spacing(8);
+
+        sections = sections.push(toggle(
+            format!("Redirects ({})", outcome.redirects.len()),
+            self.show_redirects,
+            Message::ToggleRedirects,
+        ));
+        if self.show_redirects {
+            let hops: Element<'a, Message> = if outcome.redirects.is_empty() {
+                text("No redirects").size(14).color(subtitle).into()
+            } else {
+                column(outcome.redirects.iter().map(|hop| {
+                    row![
+                        text(hop.status.to_string()).size(14).font(mono).width(48),
+                        text(format!("{} → {}", hop.url, hop.location))
+                            .size(14)
+                            .font(mono)
+                            .width(Length::Fill),
+                        text(format!("{:.0?}", hop.elapsed))
+                            .size(14)
+                            .font(mono)
+                            .color(subtitle),
+                    ]
+                    .spacing(12)
+                    .into()
+                }))
+                .spacing(4)
+                .into()
+            };
+            sections = sections.push(hops);
+        }
+
+        sections = sections.push(toggle(
+            format!("Response headers ({})", outcome.headers.len()),
+            self.show_headers,
+            Message::ToggleHeaders,
+        ));
+        if self.show_headers {
+            sections = sections.push(
+                column(outcome.headers.iter().map(|(name, value)| {
+                    row![
+                        text(name).size(14).font(mono).color(subtitle).width(240),
+                        text(value).size(14).font(mono).width(Length::Fill),
+                    ]
+                    .spacing(12)
+                    .into()
+                }))
+                .spacing(4),
+            );
+        }
+
+        scrollable(sections).height(Length::Shrink).into()
+    }
+}
+
+impl Default for PagePoker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    MethodChanged(Method),
+    UrlChanged(String),
+    SecureChanged(bool),
+    ModeChanged(Mode),
+    TimeoutChanged(Timeout),
+    FollowRedirectsChanged(bool),
+    UseCacheChanged(bool),
+    BatchChanged(bool),
+    BatchEdited(text_editor::Action),
+    BatchFileChanged(String),
+    LoadBatchFile,
+    BatchFileLoaded(Result<String, String>),
+    SortBy(Column),
+    ToggleRedirects,
+    ToggleHeaders,
+    ToggleRequest,
+    AddHeader,
+    RemoveHeader(usize),
+    HeaderNameChanged(usize, String),
+    HeaderValueChanged(usize, String),
+    BodyKindChanged(BodyKind),
+    BodyEdited(text_editor::Action),
+    ResponseBodyAction(text_editor::Action),
+    Action,
+    Cancelled,
+    Progress(Progress),
+    Result(Box<Result<FetchOutcome, FetchError>>),
+    BatchResult(usize, Box<Result<FetchOutcome, FetchError>>),
+    FetchAssets,
+    AssetResult(usize, Box<Result<FetchOutcome, FetchError>>),
+    ToggleAssets(AssetKind),
+    Replay(usize),
+    ExportHistory,
+    HistoryExported(Result<PathBuf, String>),
+    /// Show the model as it was after the n-th logged message.
+    Scrub(usize),
+    Live,
+    ReplayFromHere,
+}
@@ -0,0 +1,143 @@
+use iced::{
+    Element, Length,
+    widget::{button, column, container, row, scrollable, text, text_editor, text_input},
+};
+
+use crate::{Column, Message, PagePoker};
+
+/// Fetches running at once in batch mode; the rest wait their turn.
+pub const CONCURRENCY: usize = 4;
+
+impl Column {
+    fn label(self) -> &'static str {
+        match self {
+            Column::Url => "URL",
+            Column::Status => "Status",
+            Column::Title => "Title",
+            Column::Size => "Size",
+            Column::Time => "Time",
+        }
+    }
+
+    fn width(self) -> Length {
+        match self {
+            Column::Url | Column::Title => Length::FillPortion(1),
+            Column::Status => Length::Fixed(70.0),
+            Column::Size | Column::Time => Length::Fixed(100.0),
+        }
+    }
+}
+
+impl PagePoker {
+    /// URL list editor, shown instead of the single URL input.
+    pub(crate) fn view_batch_input(&self) -> Element<'_, Message> {
+        let mono = self.style.mono_font;
+
+        let mut load = row![
+            text_input("Load URLs from file (one per line)", &self.batch_file)
+                .on_input(Message::BatchFileChanged)
+                .on_submit(Message::LoadBatchFile),
+            button("Load").on_press(Message::LoadBatchFile),
+        ]
+        .spacing(12)
+        .align_y(iced::Alignment::Center);
+        if let Some(error) = &self.batch_file_error {
+            load = load.push(text(error).size(14).color(self.style.error_color));
+        }
+
+        column![
+            text_editor(&self.batch_input)
+                .placeholder("One URL per line")
+                .on_action(Message::BatchEdited)
+                .font(mono)
+                .height(120),
+            load,
+        ]
+        .spacing(8)
+        .padding(iced::Padding::ZERO.top(12))
+        .into()
+    }
+
+    /// Sortable table of every URL in the batch.
+    pub(crate) fn view_batch(&self) -> Element<'_, Message> {
+        let mono = self.style.mono_font;
+        let subtitle = self.style.subtitle_color;
+        let sort = self.model.sort;
+
+        if self.model.rows.is_empty() {
+            return text("Enter some URLs and click Get")
+                .size(self.style.text_size)
+                .into();
+        }
+
+        let header = row(Column::ALL.map(|column| {
+            let arrow = match (sort.column == column, sort.descending) {
+                (false, _) => "",
+                (true, false) => " ▲",
+                (true, true) => " ▼",
+            };
+            button(
+                text(format!("{}{arrow}", column.label()))
+                    .size(14)
+                    .font(mono),
+            )
+            .style(button::text)
+            .padding(0)
+            .width(column.width())
+            .on_press(Message::SortBy(column))
+            .into()
+        }))
+        .spacing(12);
+
+        let rows = sort.order(&self.model.rows).into_iter().map(|index| {
+            let entry = &self.model.rows[index];
+            let cell = |column: Column, content: String| {
+                text(content).size(14).font(mono).width(column.width())
+            };
+            let url = cell(Column::Url, entry.url.clone());
+            let empty = |column: Column| cell(column, String::new());
+
+            let cells = match entry.result.as_deref() {
+                None => {
+                    let status = if self.batch_requests.contains_key(&index) {
+                        "..."
+                    } else {
+                        "—"
+                    };
+                    row![
+                        url,
+                        cell(Column::Status, status.into()).color(subtitle),
+                        empty(Column::Title),
+                        empty(Column::Size),
+                        empty(Column::Time),
+                    ]
+                }
+                Some(Ok(outcome)) => row![
+                    url,
+                    cell(Column::Status, outcome.status.to_string()),
+                    cell(
+                        Column::Title,
+                        outcome.metadata.title.clone().unwrap_or_default()
+                    ),
+                    cell(
+                        Column::Size,
+                        humansize::format_size(outcome.size, humansize::DECIMAL)
+                    ),
+                    cell(Column::Time, format!("{:.0?}", outcome.elapsed)),
+                ],
+                Some(Err(error)) => row![
+                    url,
+                    cell(Column::Status, "Error".into()).color(self.style.error_color),
+                    cell(Column::Title, error.to_string()).color(self.style.error_color),
+                    empty(Column::Size),
+                    empty(Column::Time),
+                ],
+            };
+            cells.spacing(12).into()
+        });
+
+        container(scrollable(column![header, column(rows).spacing(4)].spacing(8)).height(200))
+            .width(iced::Fill)
+            .into()
+    }
+}
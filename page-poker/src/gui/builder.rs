@@ -0,0 +1,142 @@
+use iced::{
+    Element, Length,
+    widget::{button, column, pick_list, row, text, text_editor, text_input},
+};
+use strum::IntoEnumIterator;
+
+use crate::{BodyKind, FetchOutcome, Message, PagePoker};
+
+/// Highlighter syntax for a content type, plain text when unknown.
+fn syntax(content_type: &str) -> &'static str {
+    [
+        ("json", "json"),
+        ("html", "html"),
+        ("xml", "xml"),
+        ("css", "css"),
+        ("javascript", "js"),
+    ]
+    .into_iter()
+    .find(|(kind, _)| content_type.contains(kind))
+    .map_or("txt", |(_, syntax)| syntax)
+}
+
+impl PagePoker {
+    /// Collapsible editor for the request headers and body.
+    pub(crate) fn view_request_builder(&self) -> Element<'_, Message> {
+        let mono = self.style.mono_font;
+        let subtitle = self.style.subtitle_color;
+
+        let headers = self
+            .model
+            .headers
+            .iter()
+            .filter(|(name, _)| !name.trim().is_empty())
+            .count();
+        let arrow = if self.show_request { "▾" } else { "▸" };
+        let toggle = button(
+            text(format!(
+                "{arrow} Request headers ({headers}) & body ({})",
+                self.model.body_kind
+            ))
+            .size(16)
+            .font(mono),
+        )
+        .style(button::text)
+        .padding(0)
+        .on_press(Message::ToggleRequest);
+
+        if !self.show_request {
+            return column![toggle].padding(iced::Padding::ZERO.top(12)).into();
+        }
+
+        let rows = self
+            .model
+            .headers
+            .iter()
+            .enumerate()
+            .map(|(index, (name, value))| {
+                row![
+                    text_input("Header", name)
+                        .on_input(move |name| Message::HeaderNameChanged(index, name))
+                        .font(mono)
+                        .width(Length::FillPortion(1)),
+                    text_input("Value", value)
+                        .on_input(move |value| Message::HeaderValueChanged(index, value))
+                        .font(mono)
+                        .width(Length::FillPortion(2)),
+                    button(text("×").size(14))
+                        .padding([2, 8])
+                        .on_press(Message::RemoveHeader(index)),
+                ]
+                .spacing(8)
+                .align_y(iced::Alignment::Center)
+                .into()
+            });
+
+        let mut builder = column![
+            toggle,
+            column(rows).spacing(4),
+            row![
+                button(text("Add header").size(14))
+                    .padding([2, 8])
+                    .on_press(Message::AddHeader),
+                text("Body").size(14).color(subtitle),
+                pick_list(
+                    BodyKind::iter().collect::<Vec<_>>(),
+                    Some(self.model.body_kind),
+                    Message::BodyKindChanged
+                ),
+            ]
+            .spacing(12)
+            .align_y(iced::Alignment::Center),
+        ]
+        .spacing(8)
+        .padding(iced::Padding::ZERO.top(12));
+
+        let placeholder = match self.model.body_kind {
+            BodyKind::None => None,
+            BodyKind::Json => Some(("{\"name\": \"Ferris\"}", "json")),
+            BodyKind::Form => Some(("name=Ferris (one field per line)", "txt")),
+        };
+        if let Some((placeholder, syntax)) = placeholder {
+            builder = builder.push(
+                text_editor(&self.request_body)
+                    .placeholder(placeholder)
+                    .on_action(Message::BodyEdited)
+                    .highlight(syntax, self.style.highlight_theme)
+                    .font(mono)
+                    .size(14)
+                    .height(100),
+            );
+        }
+
+        builder.into()
+    }
+
+    /// The response body, highlighted by its content type. It can be
+    /// selected and scrolled but not edited.
+    pub(crate) fn view_response_body<'a>(&'a self, outcome: &FetchOutcome) -> Element<'a, Message> {
+        if outcome.body.is_empty() {
+            let message = if outcome.size == 0 {
+                "Empty body"
+            } else {
+                "Binary body, not shown"
+            };
+            return text(message)
+                .size(self.style.text_size)
+                .color(self.style.subtitle_color)
+                .into();
+        }
+
+        text_editor(&self.response_body)
+            .on_action(Message::ResponseBodyAction)
+            .highlight(
+                syntax(outcome.header("content-type").unwrap_or("html")),
+                self.style.highlight_theme,
+            )
+            .font(self.style.mono_font)
+            .size(14)
+            .height(240)
+            .into()
+    }
+}
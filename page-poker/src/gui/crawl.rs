@@ -0,0 +1,132 @@
+use iced::{
+    Element, Length,
+    widget::{button, column, row, scrollable, text},
+};
+
+use crate::{Asset, AssetKind, FetchError, Message, PagePoker, Weight};
+
+impl AssetKind {
+    fn label(self) -> &'static str {
+        match self {
+            AssetKind::Stylesheet => "Stylesheets",
+            AssetKind::Script => "Scripts",
+            AssetKind::Image => "Images",
+            AssetKind::Link => "Links",
+        }
+    }
+}
+
+impl PagePoker {
+    /// The page and what it links to as a tree, one collapsible branch per
+    /// kind of asset.
+    pub(crate) fn view_assets(&self, document: usize) -> Element<'_, Message> {
+        let mono = self.style.mono_font;
+        let subtitle = self.style.subtitle_color;
+        let size = |bytes: usize| humansize::format_size(bytes, humansize::DECIMAL);
+        let assets = &self.model.assets;
+
+        if assets.is_empty() {
+            return text("No links or assets found")
+                .size(self.style.text_size)
+                .into();
+        }
+
+        let weight = Weight::of(document, assets);
+        let fetching = !self.asset_requests.is_empty();
+        let total = format!(
+            "Page weight: {} · {} of {} checked · {} broken",
+            size(weight.bytes),
+            weight.checked,
+            assets.len(),
+            weight.broken
+        );
+        let header = row![
+            text(total).size(self.style.text_size),
+            button(text("Fetch assets").size(14))
+                .padding([2, 8])
+                .on_press_maybe(
+                    (!fetching && weight.checked < assets.len()).then_some(Message::FetchAssets)
+                ),
+        ]
+        .spacing(12)
+        .align_y(iced::Alignment::Center);
+
+        let mut tree = column![
+            text(format!("{} ({})", self.model.url, size(document)))
+                .size(16)
+                .font(mono)
+        ]
+        .spacing(4);
+        for kind in AssetKind::ALL {
+            let branch: Vec<(usize, &Asset)> = assets
+                .iter()
+                .enumerate()
+                .filter(|(_, asset)| asset.kind == kind)
+                .collect();
+            if branch.is_empty() {
+                continue;
+            }
+
+            let expanded = !self.collapsed_assets.contains(&kind);
+            let arrow = if expanded { "▾" } else { "▸" };
+            let mut label = format!("{arrow} {} ({})", kind.label(), branch.len());
+            if kind.is_loaded() {
+                let bytes: usize = branch.iter().map(|(_, asset)| asset.size()).sum();
+                label.push_str(&format!(" · {}", size(bytes)));
+            }
+            let broken = branch.iter().filter(|(_, asset)| asset.is_broken()).count();
+            if broken > 0 {
+                label.push_str(&format!(" · {broken} broken"));
+            }
+            tree = tree.push(
+                button(text(label).size(14).font(mono))
+                    .style(button::text)
+                    .padding(iced::Padding::ZERO.left(16))
+                    .on_press(Message::ToggleAssets(kind)),
+            );
+            if !expanded {
+                continue;
+            }
+
+            for (index, asset) in branch {
+                let (status, color) = match &asset.checked {
+                    None if self.asset_requests.contains_key(&index) => {
+                        ("...".into(), Some(subtitle))
+                    }
+                    None => ("—".into(), Some(subtitle)),
+                    Some(Ok(snapshot)) => (snapshot.status.to_string(), None),
+                    Some(Err(FetchError::Status { code, .. })) => {
+                        (code.to_string(), Some(self.style.error_color))
+                    }
+                    Some(Err(_)) => ("Error".into(), Some(self.style.error_color)),
+                };
+                let detail = match &asset.checked {
+                    Some(Ok(snapshot)) => size(snapshot.size),
+                    // The code is already in the status column
+                    Some(Err(FetchError::Status { reason, .. })) => reason.clone(),
+                    Some(Err(error)) => error.to_string(),
+                    None => String::new(),
+                };
+                tree = tree.push(
+                    row![
+                        text(status)
+                            .size(14)
+                            .font(mono)
+                            .color_maybe(color)
+                            .width(48),
+                        text(&asset.url).size(14).font(mono).width(Length::Fill),
+                        text(detail).size(14).font(mono).color_maybe(color),
+                    ]
+                    .spacing(12)
+                    .padding(iced::Padding::ZERO.left(40)),
+                );
+            }
+        }
+
+        // Keep the sizes clear of the scrollbar
+        let tree = tree.padding(iced::Padding::ZERO.right(16));
+        column![header, scrollable(tree).height(200)]
+            .spacing(8)
+            .into()
+    }
+}
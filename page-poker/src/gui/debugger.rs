@@ -0,0 +1,149 @@
+use std::cmp::Ordering;
+
+use iced::{
+    Element, Length, Task,
+    widget::{button, column, row, scrollable, slider, space, text},
+};
+
+use crate::{FetchError, FetchOutcome, Message, PagePoker, UrlAnalyzer};
+
+/// A logged message and the model right after it was handled. Results are
+/// behind an `Arc`, so the snapshots share the fetched bodies.
+#[derive(Debug, Clone)]
+pub(crate) struct LogEntry {
+    pub message: String,
+    pub model: UrlAnalyzer,
+}
+
+impl FetchOutcome {
+    /// How a fetch went, e.g. `Ok(200, 1256 bytes)`. Headers and metadata
+    /// would flood the log.
+    pub(crate) fn log_summary(result: &Result<Self, FetchError>) -> String {
+        match result {
+            Ok(outcome) => format!("Ok({}, {} bytes)", outcome.status, outcome.size),
+            Err(error) => format!("Err({error:?})"),
+        }
+    }
+}
+
+impl Message {
+    /// Sent by a task rather than by the user.
+    pub(crate) fn is_background(&self) -> bool {
+        matches!(
+            self,
+            Message::Progress(_)
+                | Message::Result(_)
+                | Message::BatchResult(..)
+                | Message::AssetResult(..)
+                | Message::BatchFileLoaded(_)
+                | Message::HistoryExported(_)
+        )
+    }
+}
+
+impl PagePoker {
+    /// Apply a message and snapshot the model into the log entries it added.
+    pub(crate) fn record(&mut self, message: Message) -> Task<Message> {
+        let logged = self.logged;
+        let task = self.apply(message);
+        let added = (self.logged - logged).min(self.message_log.len());
+        for entry in self.message_log.iter_mut().rev().take(added) {
+            entry.model = self.model.clone();
+        }
+        task
+    }
+
+    /// Show the model as it was after the message at `index`.
+    pub(crate) fn scrub(&mut self, index: usize) -> Task<Message> {
+        let Some(entry) = self.message_log.get(index) else {
+            return Task::none();
+        };
+        let snapshot = entry.model.clone();
+        if self.live.is_none() {
+            self.live = Some(self.model.clone());
+        }
+        self.restore(snapshot);
+        self.scrub = Some(index);
+        Task::none()
+    }
+
+    pub(crate) fn go_live(&mut self) -> Task<Message> {
+        if let Some(live) = self.live.take() {
+            self.restore(live);
+        }
+        self.scrub = None;
+        Task::none()
+    }
+
+    /// Continue from the snapshot being shown, forgetting every message
+    /// after it and any request still in flight.
+    pub(crate) fn replay_from_here(&mut self) -> Task<Message> {
+        let Some(index) = self.scrub.take() else {
+            return Task::none();
+        };
+        self.live = None;
+        self.message_log.truncate(index + 1);
+        self.request = None;
+        self.requested = None;
+        self.progress = None;
+        self.batch_requests.clear();
+        self.batch_queue.clear();
+        self.asset_requests.clear();
+        self.asset_queue.clear();
+        self.model.loading = false;
+        self.log_message(format!("ReplayFromHere({index})"));
+        Task::none()
+    }
+
+    /// The message log with a slider to scrub through it.
+    pub(crate) fn view_message_log(&self) -> Element<'_, Message> {
+        let mono = self.style.mono_font;
+        let subtitle = self.style.subtitle_color;
+
+        if self.message_log.is_empty() {
+            return text("Messages will appear here...")
+                .size(14)
+                .color(subtitle)
+                .into();
+        }
+
+        let last = self.message_log.len() - 1;
+        let position = self.scrub.unwrap_or(last);
+
+        let controls = row![
+            slider(0.0..=last as f64, position as f64, |index| {
+                Message::Scrub(index as usize)
+            })
+            .width(Length::Fill),
+            button(text("Live").size(14))
+                .padding([2, 8])
+                .on_press_maybe(self.scrub.map(|_| Message::Live)),
+            button(text("Replay from here").size(14))
+                .padding([2, 8])
+                .on_press_maybe(self.scrub.map(|_| Message::ReplayFromHere)),
+        ]
+        .spacing(8)
+        .align_y(iced::Alignment::Center);
+
+        let entries = self.message_log.iter().enumerate().map(|(index, entry)| {
+            // Highlight the message being looked at and dim its future
+            let color = self.scrub.and_then(|scrub| match index.cmp(&scrub) {
+                Ordering::Less => None,
+                Ordering::Equal => Some(self.style.accent_color),
+                Ordering::Greater => Some(subtitle),
+            });
+            row![
+                text(&entry.message).size(14).font(mono).color_maybe(color),
+                space().width(Length::Fill)
+            ]
+            .into()
+        });
+
+        column![
+            controls,
+            scrollable(column(entries).spacing(4)).height(Length::Fill),
+        ]
+        .spacing(8)
+        .into()
+    }
+}
@@ -0,0 +1,83 @@
+use iced::{
+    Element, Length,
+    widget::{button, column, text},
+};
+
+use crate::{Message, PagePoker};
+
+impl PagePoker {
+    /// Completed requests, newest first; clicking one replays it.
+    pub(crate) fn view_history(&self) -> Element<'_, Message> {
+        let mono = self.style.mono_font;
+        let subtitle = self.style.subtitle_color;
+
+        if self.history.is_empty() {
+            return text("Completed requests will appear here...")
+                .size(14)
+                .color(subtitle)
+                .into();
+        }
+
+        let mut entries = column![].spacing(8);
+        if let Some(export) = &self.history_export {
+            entries = entries.push(match export {
+                Ok(path) => text(format!("Saved {}", path.display()))
+                    .size(14)
+                    .color(subtitle),
+                Err(e) => text(format!("Export failed: {e}"))
+                    .size(14)
+                    .color(self.style.error_color),
+            });
+        }
+
+        for (index, entry) in self.history.newest_first() {
+            let result = match &entry.outcome {
+                Ok(snapshot) => text(format!(
+                    "{} · {} · {:.0?}",
+                    snapshot.status,
+                    humansize::format_size(snapshot.size, humansize::DECIMAL),
+                    snapshot.elapsed
+                ))
+                .size(14)
+                .font(mono),
+                Err(error) => text(error.to_string())
+                    .size(14)
+                    .color(self.style.error_color),
+            };
+
+            let mut details = column![
+                text(format!(
+                    "{} · {} {}",
+                    entry.clock(),
+                    entry.method,
+                    entry.url
+                ))
+                .size(14)
+                .font(mono),
+                result,
+            ]
+            .spacing(2);
+            if let Some(previous) = self.history.previous(index) {
+                details = details.push(
+                    text(format!(
+                        "vs {}: {}",
+                        previous.clock(),
+                        entry.diff(previous).join(", ")
+                    ))
+                    .size(12)
+                    .color(subtitle),
+                );
+            }
+
+            entries = entries.push(
+                button(details)
+                    .style(button::text)
+                    .padding(0)
+                    .width(Length::Fill)
+                    .on_press(Message::Replay(index)),
+            );
+        }
+
+        entries.into()
+    }
+}
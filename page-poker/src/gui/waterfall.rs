@@ -0,0 +1,103 @@
+use std::time::Duration;
+
+use iced::{
+    Color, Font, Point, Rectangle, Size,
+    alignment::Vertical,
+    widget::canvas::{self, Frame, Path},
+};
+
+use crate::Timing;
+
+/// Height of one phase's row.
+const ROW_HEIGHT: f32 = 22.0;
+
+const LABEL_WIDTH: f32 = 130.0;
+const DURATION_WIDTH: f32 = 70.0;
+
+/// One color per phase, cycled if there are more phases than colors.
+const COLORS: [Color; 6] = [
+    Color::from_rgb(0.55, 0.55, 0.55),
+    Color::from_rgb(0.16, 0.63, 0.60),
+    Color::from_rgb(0.85, 0.52, 0.13),
+    Color::from_rgb(0.62, 0.40, 0.78),
+    Color::from_rgb(0.27, 0.52, 0.85),
+    Color::from_rgb(0.40, 0.70, 0.30),
+];
+
+/// Phases of a request as a waterfall: one row per phase, each bar starting
+/// where the previous one ended.
+pub struct Waterfall {
+    pub phases: Vec<(&'static str, Duration)>,
+    pub font: Font,
+    pub label_color: Color,
+}
+
+impl Waterfall {
+    pub fn new(timing: &Timing, font: Font, label_color: Color) -> Self {
+        Self {
+            phases: timing.phases(),
+            font,
+            label_color,
+        }
+    }
+
+    pub fn height(&self) -> f32 {
+        self.phases.len() as f32 * ROW_HEIGHT
+    }
+}
+
+impl<Message> canvas::Program<Message> for Waterfall {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &iced::Renderer,
+        _theme: &iced::Theme,
+        bounds: Rectangle,
+        _cursor: iced::mouse::Cursor,
+    ) -> Vec<canvas::Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+
+        let total: Duration = self.phases.iter().map(|(_, duration)| *duration).sum();
+        let track = (bounds.width - LABEL_WIDTH - DURATION_WIDTH).max(0.0);
+        let scale = if total.is_zero() {
+            0.0
+        } else {
+            track / total.as_secs_f32()
+        };
+
+        let mut offset = 0.0;
+        for (row, (label, duration)) in self.phases.iter().enumerate() {
+            let top = row as f32 * ROW_HEIGHT;
+            let middle = top + ROW_HEIGHT / 2.0;
+            let text = |content: String, x: f32| canvas::Text {
+                content,
+                position: Point::new(x, middle),
+                color: self.label_color,
+                size: 14.0.into(),
+                font: self.font,
+                align_y: Vertical::Center,
+                ..canvas::Text::default()
+            };
+
+            frame.fill_text(text(label.to_string(), 0.0));
+
+            // Keep instant phases visible as a sliver
+            let width = (duration.as_secs_f32() * scale).max(2.0);
+            frame.fill(
+                &Path::rectangle(
+                    Point::new(LABEL_WIDTH + offset, top + 4.0),
+                    Size::new(width, ROW_HEIGHT - 8.0),
+                ),
+                COLORS[row % COLORS.len()],
+            );
+
+            frame.fill_text(text(format!("{duration:.0?}"), LABEL_WIDTH + track + 8.0));
+
+            offset += duration.as_secs_f32() * scale;
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
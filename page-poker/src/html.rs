@@ -0,0 +1,315 @@
+use reqwest::Url;
+use scraper::{ElementRef, Html, Selector};
+use serde::{Deserialize, Serialize};
+
+use crate::AssetKind;
+
+const HTML_NAMESPACE: &str = "http://www.w3.org/1999/xhtml";
+
+/// Everything the Metadata mode shows about a page.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Metadata {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    /// `og:*` properties in document order, e.g. `("og:image", "https://...")`.
+    pub open_graph: Vec<(String, String)>,
+    pub canonical: Option<String>,
+    /// `<link rel=icon>`, or `/favicon.ico` where browsers look without one.
+    pub favicon: Option<String>,
+}
+
+impl Metadata {
+    /// Parse a document; relative links are resolved against `base`, usually
+    /// the final URL after redirects, or the document's `<base href>`.
+    pub fn parse(source: &str, url: Option<&Url>) -> Self {
+        let document = Html::parse_document(source);
+        let base = document_base(&document, url);
+        let base = base.as_ref();
+
+        let links = select(&document, "link[rel][href]");
+        let link = |rel: &str| {
+            links
+                .iter()
+                .find(|link| has_rel(link, rel))
+                .and_then(|link| link.value().attr("href"))
+                .map(|href| resolve(href, base))
+        };
+
+        let metas = select(&document, "meta[content]");
+
+        Self {
+            title: title(&document),
+            description: metas
+                .iter()
+                .find(|meta| {
+                    meta.value()
+                        .attr("name")
+                        .is_some_and(|name| name.eq_ignore_ascii_case("description"))
+                })
+                .and_then(|meta| meta.value().attr("content"))
+                .map(collapse_whitespace),
+            open_graph: metas
+                .iter()
+                .filter_map(|meta| {
+                    let property = meta.value().attr("property")?;
+                    let content = meta.value().attr("content")?;
+                    property
+                        .to_ascii_lowercase()
+                        .starts_with("og:")
+                        .then(|| (property.to_string(), content.trim().to_string()))
+                })
+                .collect(),
+            canonical: link("canonical"),
+            // Browsers look on the page's own host, whatever its `<base>`
+            favicon: link("icon").or_else(|| {
+                url.and_then(|url| url.join("/favicon.ico").ok())
+                    .map(String::from)
+            }),
+        }
+    }
+}
+
+/// The document title: the first HTML `<title>`, ignoring SVG `<title>`s,
+/// with whitespace collapsed the way browsers show it.
+fn title(document: &Html) -> Option<String> {
+    select(document, "title")
+        .into_iter()
+        .find(|title| title.value().name.ns.as_ref() == HTML_NAMESPACE)
+        .map(|title| collapse_whitespace(&title.text().collect::<String>()))
+        .filter(|title| !title.is_empty())
+}
+
+/// What relative URLs are resolved against: the first `<base href>`, itself
+/// resolved against the document's URL, or that URL.
+fn document_base(document: &Html, url: Option<&Url>) -> Option<Url> {
+    let href = select(document, "base[href]")
+        .first()
+        .and_then(|base| base.value().attr("href"))
+        .map(str::trim);
+    let base = match (href, url) {
+        (Some(href), Some(url)) => url.join(href).ok(),
+        (Some(href), None) => Url::parse(href).ok(),
+        (None, _) => None,
+    };
+    base.or_else(|| url.cloned())
+}
+
+fn select<'a>(document: &'a Html, selector: &str) -> Vec<ElementRef<'a>> {
+    let selector = Selector::parse(selector).expect("valid selector");
+    document.select(&selector).collect()
+}
+
+/// `rel` is a case-insensitive, space-separated list, e.g. `shortcut icon`.
+fn has_rel(link: &ElementRef<'_>, rel: &str) -> bool {
+    link.value().attr("rel").is_some_and(|value| {
+        value
+            .split_ascii_whitespace()
+            .any(|token| token.eq_ignore_ascii_case(rel))
+    })
+}
+
+fn resolve(href: &str, base: Option<&Url>) -> String {
+    base.and_then(|base| base.join(href.trim()).ok())
+        .map(String::from)
+        .unwrap_or_else(|| href.trim().to_string())
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Stylesheets, scripts, images and outgoing links of a document, resolved
+/// against its `<base href>` or `url`. Only http(s) URLs are kept, each once
+/// per kind.
+pub fn assets(source: &str, url: Option<&Url>) -> Vec<(AssetKind, String)> {
+    let document = Html::parse_document(source);
+    let base = document_base(&document, url);
+    let base = base.as_ref();
+
+    let stylesheets = select(&document, "link[rel][href]")
+        .into_iter()
+        .filter(|link| has_rel(link, "stylesheet"))
+        .filter_map(|link| link.value().attr("href"))
+        .map(|href| (AssetKind::Stylesheet, href))
+        .collect::<Vec<_>>();
+    let sources = [
+        (AssetKind::Script, "script[src]", "src"),
+        (AssetKind::Image, "img[src]", "src"),
+        (AssetKind::Link, "a[href]", "href"),
+    ]
+    .into_iter()
+    .flat_map(|(kind, selector, attribute)| {
+        select(&document, selector)
+            .into_iter()
+            .filter_map(move |element| element.value().attr(attribute))
+            .map(move |url| (kind, url))
+    });
+
+    let mut assets: Vec<(AssetKind, String)> = Vec::new();
+    for (kind, url) in stylesheets.into_iter().chain(sources) {
+        let url = resolve(url, base);
+        let is_web = url.starts_with("http://") || url.starts_with("https://");
+        // Links back into the same page only differ by their fragment
+        let url = url.split('#').next().unwrap_or_default().to_string();
+        if is_web && !assets.contains(&(kind, url.clone())) {
+            assets.push((kind, url));
+        }
+    }
+    assets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn base() -> Url {
+        Url::parse("https://example.com/docs/page.html").expect("valid URL")
+    }
+
+    #[test]
+    fn title_ignores_tag_case_and_decodes_entities() {
+        let html =
+            "<HTML><HEAD><TITLE lang=en>  Fish &amp;\n Chips &#8212; Menu </TITLE></HEAD></HTML>";
+        let metadata = Metadata::parse(html, None);
+        assert_eq!(metadata.title.as_deref(), Some("Fish & Chips — Menu"));
+    }
+
+    #[test]
+    fn svg_title_does_not_win_over_document_title() {
+        let html = "<body><svg><title>Logo</title></svg><title>Home</title></body>";
+        assert_eq!(Metadata::parse(html, None).title.as_deref(), Some("Home"));
+
+        let svg_only = "<body><svg><title>Logo</title></svg></body>";
+        assert_eq!(Metadata::parse(svg_only, None).title, None);
+    }
+
+    #[test]
+    fn description_and_open_graph() {
+        let html = r#"
+            <meta name="Description" content=" A page
+                about things ">
+            <meta property="og:title" content="Things">
+            <meta property="og:description" content="All about things">
+            <meta property="twitter:card" content="summary">
+        "#;
+        let metadata = Metadata::parse(html, None);
+        assert_eq!(metadata.description.as_deref(), Some("A page about things"));
+        assert_eq!(
+            metadata.open_graph,
+            vec![
+                ("og:title".to_string(), "Things".to_string()),
+                ("og:description".to_string(), "All about things".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn canonical_and_favicon_are_resolved() {
+        let html = r#"
+            <link rel="canonical" href="/docs/">
+            <link rel="Shortcut Icon" href="img/icon.png">
+        "#;
+        let metadata = Metadata::parse(html, Some(&base()));
+        assert_eq!(
+            metadata.canonical.as_deref(),
+            Some("https://example.com/docs/")
+        );
+        assert_eq!(
+            metadata.favicon.as_deref(),
+            Some("https://example.com/docs/img/icon.png")
+        );
+    }
+
+    #[test]
+    fn favicon_falls_back_to_favicon_ico() {
+        let metadata = Metadata::parse("<title>No icon</title>", Some(&base()));
+        assert_eq!(
+            metadata.favicon.as_deref(),
+            Some("https://example.com/favicon.ico")
+        );
+        assert_eq!(
+            Metadata::parse("<title>No icon</title>", None).favicon,
+            None
+        );
+    }
+
+    #[test]
+    fn assets_are_resolved_against_the_base() {
+        let html = r##"
+            <link rel="stylesheet" href="style.css">
+            <link rel="preload" href="font.woff2">
+            <script src="../app.js"></script>
+            <script>inline()</script>
+            <img src="//cdn.example.net/logo.png">
+            <a href="#top">Top</a>
+            <a href="/about/#team">About</a>
+            <a href="/about/">About again</a>
+            <a href="mailto:hi@example.com">Mail</a>
+        "##;
+        assert_eq!(
+            assets(html, Some(&base())),
+            vec![
+                (
+                    AssetKind::Stylesheet,
+                    "https://example.com/docs/style.css".to_string()
+                ),
+                (AssetKind::Script, "https://example.com/app.js".to_string()),
+                (
+                    AssetKind::Image,
+                    "https://cdn.example.net/logo.png".to_string()
+                ),
+                (
+                    AssetKind::Link,
+                    "https://example.com/docs/page.html".to_string()
+                ),
+                (AssetKind::Link, "https://example.com/about/".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn base_href_changes_what_urls_resolve_against() {
+        let html = r#"
+            <head>
+                <base href="/static/v2/">
+                <base href="https://ignored.example.org/">
+                <link rel="canonical" href="../">
+                <link rel="stylesheet" href="style.css">
+            </head>
+            <img src="logo.png">
+        "#;
+        let metadata = Metadata::parse(html, Some(&base()));
+        assert_eq!(
+            metadata.canonical.as_deref(),
+            Some("https://example.com/static/")
+        );
+        assert_eq!(
+            metadata.favicon.as_deref(),
+            Some("https://example.com/favicon.ico")
+        );
+        assert_eq!(
+            assets(html, Some(&base())),
+            vec![
+                (
+                    AssetKind::Stylesheet,
+                    "https://example.com/static/v2/style.css".to_string()
+                ),
+                (
+                    AssetKind::Image,
+                    "https://example.com/static/v2/logo.png".to_string()
+                ),
+            ]
+        );
+
+        // Without the page's URL only an absolute one helps
+        let absolute = r#"<base href="https://cdn.example.net/"><img src="logo.png">"#;
+        assert_eq!(
+            assets(absolute, None),
+            vec![(
+                AssetKind::Image,
+                "https://cdn.example.net/logo.png".to_string()
+            )]
+        );
+    }
+}
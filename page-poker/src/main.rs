@@ -1,45 +1,39 @@
-use iced::{Element, Font, Task};
-use page_poker::{FIRA_MONO_BYTES, PagePoker};
+#[cfg(feature = "gui")]
+mod app;
+mod cli;
 
-const FIRA_MONO: Font = Font::with_name("Fira Mono");
+fn main() {
+    let args = match cli::Args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => exit_with(&format!("{e}\n\n{}", cli::USAGE)),
+    };
 
-struct App {
-    poker: PagePoker,
-}
-
-#[derive(Debug, Clone)]
-enum Message {
-    Poker(page_poker::Message),
-}
-
-fn main() -> iced::Result {
-    iced::application(App::default, App::update, App::view)
-        .title("Page Poker")
-        .font(FIRA_MONO_BYTES)
-        .default_font(FIRA_MONO)
-        .run()
-}
-
-impl Default for App {
-    fn default() -> Self {
-        Self {
-            poker: PagePoker::new(),
-        }
+    if args.help {
+        println!("{}", cli::USAGE);
+        return;
     }
-}
 
-impl App {
-    fn update(&mut self, message: Message) -> Task<Message> {
-        match message {
-            Message::Poker(msg) => self.poker.update(msg).map(Message::Poker),
+    // URLs on the command line are fetched without opening a window
+    if !args.urls.is_empty() {
+        match cli::run(&args) {
+            Ok(true) => return,
+            Ok(false) => std::process::exit(1),
+            Err(e) => exit_with(&e),
         }
     }
 
-    fn view(&self) -> Element<'_, Message> {
-        iced::widget::container(self.poker.view().map(Message::Poker))
-            .padding(30)
-            .width(iced::Fill)
-            .height(iced::Fill)
-            .into()
+    #[cfg(feature = "gui")]
+    if let Err(e) = app::run(&args) {
+        exit_with(&e.to_string());
     }
+    #[cfg(not(feature = "gui"))]
+    exit_with(&format!(
+        "no URLs given, and this build has no app window (the `gui` feature)\n\n{}",
+        cli::USAGE
+    ));
+}
+
+fn exit_with(message: &str) -> ! {
+    eprintln!("error: {message}");
+    std::process::exit(2);
 }
@@ -0,0 +1,520 @@
+//! What Page Poker works with, apart from the views that show it.
+
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter};
+
+use crate::{FetchError, FetchOutcome, Mode};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Display, EnumIter, Serialize, Deserialize)]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum Method {
+    #[default]
+    Get,
+    Post,
+    Put,
+    Patch,
+    Delete,
+    Head,
+    Options,
+}
+
+impl Method {
+    /// The method a redirect is followed with. Like browsers, a 303 and,
+    /// for POST, a 301 or 302 turn the request into a GET without a body.
+    pub fn after_redirect(self, status: u16) -> Method {
+        match (self, status) {
+            (Method::Head, _) => Method::Head,
+            (_, 303) | (Method::Post, 301 | 302) => Method::Get,
+            (method, _) => method,
+        }
+    }
+}
+
+impl From<Method> for reqwest::Method {
+    fn from(method: Method) -> Self {
+        match method {
+            Method::Get => reqwest::Method::GET,
+            Method::Post => reqwest::Method::POST,
+            Method::Put => reqwest::Method::PUT,
+            Method::Patch => reqwest::Method::PATCH,
+            Method::Delete => reqwest::Method::DELETE,
+            Method::Head => reqwest::Method::HEAD,
+            Method::Options => reqwest::Method::OPTIONS,
+        }
+    }
+}
+
+/// How the request body is encoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Display, EnumIter, Serialize, Deserialize)]
+pub enum BodyKind {
+    #[default]
+    None,
+    #[strum(serialize = "JSON")]
+    Json,
+    Form,
+}
+
+/// History entries kept before the oldest ones are dropped.
+const HISTORY_CAPACITY: usize = 50;
+
+/// The parts of a fetch worth comparing between runs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Snapshot {
+    /// Final URL after redirects.
+    pub url: String,
+    pub status: u16,
+    pub size: usize,
+    pub elapsed: Duration,
+    pub title: Option<String>,
+}
+
+impl From<&FetchOutcome> for Snapshot {
+    fn from(outcome: &FetchOutcome) -> Self {
+        Self {
+            url: outcome.url.clone(),
+            status: outcome.status,
+            size: outcome.size,
+            elapsed: outcome.elapsed,
+            title: outcome.metadata.title.clone(),
+        }
+    }
+}
+
+/// A completed request, enough to replay it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    #[serde(default)]
+    pub method: Method,
+    /// As typed, so replaying adds the same scheme.
+    pub url: String,
+    pub secure: bool,
+    pub mode: Mode,
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub outcome: Result<Snapshot, FetchError>,
+}
+
+impl Entry {
+    pub fn new(
+        method: Method,
+        url: String,
+        secure: bool,
+        mode: Mode,
+        result: &Result<FetchOutcome, FetchError>,
+    ) -> Self {
+        Self {
+            method,
+            url,
+            secure,
+            mode,
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            outcome: result.as_ref().map(Snapshot::from).map_err(Clone::clone),
+        }
+    }
+
+    fn same_request(&self, other: &Entry) -> bool {
+        self.method == other.method && self.url == other.url && self.secure == other.secure
+    }
+
+    /// Time of day the request completed, e.g. `14:03:22 UTC`.
+    pub fn clock(&self) -> String {
+        let seconds = self.timestamp % (24 * 60 * 60);
+        format!(
+            "{:02}:{:02}:{:02} UTC",
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60
+        )
+    }
+
+    /// What changed since `previous`, e.g. `size 1.26 kB → 1.31 kB`.
+    pub fn diff(&self, previous: &Entry) -> Vec<String> {
+        let size = |bytes: usize| humansize::format_size(bytes, humansize::DECIMAL);
+
+        let (now, then) = match (&self.outcome, &previous.outcome) {
+            (Ok(now), Ok(then)) => (now, then),
+            (Err(now), Err(then)) if now == then => return vec!["same error".to_string()],
+            (Err(_), Ok(_)) => return vec!["failed, worked before".to_string()],
+            (Ok(_), Err(_)) => return vec!["worked, failed before".to_string()],
+            (Err(_), Err(_)) => return vec!["failed differently".to_string()],
+        };
+
+        let mut changes = Vec::new();
+        if now.status != then.status {
+            changes.push(format!("status {} → {}", then.status, now.status));
+        }
+        if now.url != then.url {
+            changes.push(format!("redirects to {}", now.url));
+        }
+        if now.title != then.title {
+            changes.push(format!(
+                "title {:?} → {:?}",
+                then.title.as_deref().unwrap_or_default(),
+                now.title.as_deref().unwrap_or_default()
+            ));
+        }
+        if now.size != then.size {
+            changes.push(format!("size {} → {}", size(then.size), size(now.size)));
+        }
+        changes.push(format!("time {:.0?} → {:.0?}", then.elapsed, now.elapsed));
+        changes
+    }
+}
+
+/// Completed requests, oldest first.
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    entries: VecDeque<Entry>,
+}
+
+impl History {
+    /// Where the app exports the history to, in the working directory.
+    pub const FILE: &str = "page-poker-history.json";
+
+    pub fn push(&mut self, entry: Entry) {
+        if self.entries.len() == HISTORY_CAPACITY {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+    }
+
+    pub fn get(&self, index: usize) -> Option<&Entry> {
+        self.entries.get(index)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Entries newest first, with their index.
+    pub fn newest_first(&self) -> impl Iterator<Item = (usize, &Entry)> {
+        self.entries.iter().enumerate().rev()
+    }
+
+    /// The last run of the same request before the entry at `index`.
+    pub fn previous(&self, index: usize) -> Option<&Entry> {
+        let entry = self.entries.get(index)?;
+        self.entries
+            .range(..index)
+            .rev()
+            .find(|previous| previous.same_request(entry))
+    }
+
+    /// Write the entries to `path` as JSON.
+    pub async fn save(self, path: PathBuf) -> Result<PathBuf, String> {
+        let json = serde_json::to_string_pretty(&self.entries).map_err(|e| e.to_string())?;
+        tokio::fs::write(&path, json)
+            .await
+            .map_err(|e| format!("{}: {e}", path.display()))?;
+        Ok(path)
+    }
+}
+
+/// One URL of a batch and, once it is done, its result.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatchRow {
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<Arc<Result<FetchOutcome, FetchError>>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Column {
+    #[default]
+    Url,
+    Status,
+    Title,
+    Size,
+    Time,
+}
+
+impl Column {
+    pub const ALL: [Column; 5] = [
+        Column::Url,
+        Column::Status,
+        Column::Title,
+        Column::Size,
+        Column::Time,
+    ];
+
+    /// Compare two rows by this column; failed fetches sort after the
+    /// successful ones.
+    fn compare(self, a: &BatchRow, b: &BatchRow) -> Ordering {
+        let outcomes = |compare: fn(&FetchOutcome, &FetchOutcome) -> Ordering| match (
+            a.result.as_deref(),
+            b.result.as_deref(),
+        ) {
+            (Some(Ok(a)), Some(Ok(b))) => compare(a, b),
+            (Some(Ok(_)), _) => Ordering::Less,
+            (_, Some(Ok(_))) => Ordering::Greater,
+            _ => Ordering::Equal,
+        };
+        match self {
+            Column::Url => a.url.cmp(&b.url),
+            Column::Status => outcomes(|a, b| a.status.cmp(&b.status)),
+            Column::Title => outcomes(|a, b| a.metadata.title.cmp(&b.metadata.title)),
+            Column::Size => outcomes(|a, b| a.size.cmp(&b.size)),
+            Column::Time => outcomes(|a, b| a.elapsed.cmp(&b.elapsed)),
+        }
+    }
+}
+
+/// Column the batch table is sorted by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Sort {
+    pub column: Column,
+    pub descending: bool,
+}
+
+impl Sort {
+    /// Clicking the current column flips the direction, another one sorts
+    /// ascending by it.
+    pub fn by(self, column: Column) -> Self {
+        Sort {
+            column,
+            descending: self.column == column && !self.descending,
+        }
+    }
+
+    /// Row indices in display order, rows still waiting for a result last.
+    pub fn order(self, rows: &[BatchRow]) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..rows.len()).collect();
+        order.sort_by(|&a, &b| {
+            let (a, b) = (&rows[a], &rows[b]);
+            let pending = a.result.is_none().cmp(&b.result.is_none());
+            let ordering = self.column.compare(a, b);
+            pending.then(if self.descending {
+                ordering.reverse()
+            } else {
+                ordering
+            })
+        });
+        order
+    }
+}
+
+/// URLs from a list, one per line; blank lines and `#` comments are skipped.
+pub fn parse_urls(list: &str) -> Vec<String> {
+    list.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(String::from)
+        .collect()
+}
+
+/// What a URL found in a page is used for, in the order they are listed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AssetKind {
+    Stylesheet,
+    Script,
+    Image,
+    /// An `<a href>`, not part of the page but possibly broken.
+    Link,
+}
+
+impl AssetKind {
+    pub const ALL: [AssetKind; 4] = [
+        AssetKind::Stylesheet,
+        AssetKind::Script,
+        AssetKind::Image,
+        AssetKind::Link,
+    ];
+
+    /// Loaded along with the page, so it counts towards its weight.
+    pub(crate) fn is_loaded(self) -> bool {
+        self != AssetKind::Link
+    }
+}
+
+/// A URL found in the fetched page and, once fetched, how that went.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Asset {
+    pub kind: AssetKind,
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checked: Option<Result<Snapshot, FetchError>>,
+    /// The server refused the HEAD request, so it is checked with a GET.
+    #[serde(skip)]
+    pub(crate) head_refused: bool,
+}
+
+impl Asset {
+    pub(crate) fn is_broken(&self) -> bool {
+        matches!(self.checked, Some(Err(_)))
+    }
+
+    pub(crate) fn size(&self) -> usize {
+        match &self.checked {
+            Some(Ok(snapshot)) => snapshot.size,
+            _ => 0,
+        }
+    }
+}
+
+/// Totals over the assets fetched so far.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Weight {
+    /// The document plus every loaded asset fetched so far.
+    pub bytes: usize,
+    pub checked: usize,
+    pub broken: usize,
+}
+
+impl Weight {
+    pub fn of(document: usize, assets: &[Asset]) -> Self {
+        Self {
+            bytes: document
+                + assets
+                    .iter()
+                    .filter(|asset| asset.kind.is_loaded())
+                    .map(Asset::size)
+                    .sum::<usize>(),
+            checked: assets
+                .iter()
+                .filter(|asset| asset.checked.is_some())
+                .count(),
+            broken: assets.iter().filter(|asset| asset.is_broken()).count(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Metadata;
+
+    fn outcome(status: u16, size: usize, millis: u64, title: &str) -> FetchOutcome {
+        FetchOutcome {
+            url: "https://example.com/".to_string(),
+            status,
+            headers: Vec::new(),
+            elapsed: Duration::from_millis(millis),
+            timing: Default::default(),
+            size,
+            metadata: Metadata {
+                title: Some(title.to_string()),
+                ..Default::default()
+            },
+            redirects: Vec::new(),
+            body: String::new(),
+            cache: Default::default(),
+        }
+    }
+
+    fn entry(url: &str, result: Result<FetchOutcome, FetchError>) -> Entry {
+        Entry::new(Method::Get, url.to_string(), true, Mode::Title, &result)
+    }
+
+    #[test]
+    fn history_finds_the_last_run_of_the_same_request() {
+        let mut history = History::default();
+        history.push(entry("a.test", Ok(outcome(200, 10, 5, "A"))));
+        history.push(entry("b.test", Ok(outcome(200, 10, 5, "B"))));
+        history.push(entry("a.test", Err(FetchError::Timeout)));
+
+        let previous = history.previous(2).unwrap();
+        assert_eq!(previous.url, "a.test");
+        assert!(previous.outcome.is_ok());
+        assert!(history.previous(1).is_none());
+        assert!(history.previous(3).is_none());
+
+        // Replays with another method are a different request
+        let mut post = entry("a.test", Ok(outcome(200, 10, 5, "A")));
+        post.method = Method::Post;
+        history.push(post);
+        assert!(history.previous(3).is_none());
+    }
+
+    #[test]
+    fn history_drops_the_oldest_entries() {
+        let mut history = History::default();
+        for i in 0..=HISTORY_CAPACITY {
+            history.push(entry(&format!("{i}.test"), Err(FetchError::Timeout)));
+        }
+
+        assert_eq!(history.newest_first().count(), HISTORY_CAPACITY);
+        assert_eq!(history.get(0).unwrap().url, "1.test");
+        let (index, newest) = history.newest_first().next().unwrap();
+        assert_eq!(
+            (index, newest.url.as_str()),
+            (HISTORY_CAPACITY - 1, "50.test")
+        );
+    }
+
+    #[test]
+    fn diff_lists_what_changed() {
+        let before = entry("a.test", Ok(outcome(200, 1260, 120, "Old")));
+        let after = entry("a.test", Ok(outcome(404, 1310, 80, "New")));
+
+        assert_eq!(
+            after.diff(&before),
+            [
+                "status 200 → 404",
+                "title \"Old\" → \"New\"",
+                "size 1.26 kB → 1.31 kB",
+                "time 120ms → 80ms",
+            ]
+        );
+        assert_eq!(before.diff(&before), ["time 120ms → 120ms"]);
+    }
+
+    #[test]
+    fn diff_compares_failures() {
+        let worked = entry("a.test", Ok(outcome(200, 10, 5, "A")));
+        let timed_out = entry("a.test", Err(FetchError::Timeout));
+        let refused = entry("a.test", Err(FetchError::Connect("refused".to_string())));
+
+        assert_eq!(timed_out.diff(&timed_out), ["same error"]);
+        assert_eq!(timed_out.diff(&refused), ["failed differently"]);
+        assert_eq!(timed_out.diff(&worked), ["failed, worked before"]);
+        assert_eq!(worked.diff(&timed_out), ["worked, failed before"]);
+    }
+
+    #[test]
+    fn sort_keeps_pending_rows_last() {
+        let row = |url: &str, result: Option<Result<FetchOutcome, FetchError>>| BatchRow {
+            url: url.to_string(),
+            result: result.map(Arc::new),
+        };
+        let rows = [
+            row("c", Some(Ok(outcome(200, 300, 10, "C")))),
+            row("a", None),
+            row("b", Some(Err(FetchError::Timeout))),
+            row("d", Some(Ok(outcome(404, 100, 30, "D")))),
+        ];
+
+        let by_size = Sort::default().by(Column::Size);
+        assert_eq!(by_size.order(&rows), [3, 0, 2, 1]);
+        // Descending still keeps the rows without a result at the end
+        assert_eq!(by_size.by(Column::Size).order(&rows), [2, 0, 3, 1]);
+        assert_eq!(Sort::default().order(&rows), [2, 0, 3, 1]);
+        assert_eq!(by_size.by(Column::Time).order(&rows), [0, 3, 2, 1]);
+    }
+
+    #[test]
+    fn history_saves_where_asked() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(History::FILE);
+        let mut history = History::default();
+        history.push(entry("a.test", Err(FetchError::Timeout)));
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let saved = runtime.block_on(history.save(path.clone())).unwrap();
+
+        assert_eq!(saved, path);
+        let entries: Vec<Entry> =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(entries[0].url, "a.test");
+    }
+}
@@ -0,0 +1,273 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, LazyLock, Mutex};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use rustls::NamedGroup;
+use rustls::client::{
+    ClientSessionMemoryCache, ClientSessionStore, Resumption, Tls12ClientSessionValue,
+    Tls13ClientSessionValue,
+};
+use rustls::pki_types::ServerName;
+use serde::{Deserialize, Serialize};
+use tower_service::Service;
+
+use crate::FetchError;
+
+/// Where the time of the final request went.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Timing {
+    /// Time spent on redirects before the final request.
+    pub redirects: Duration,
+    /// `None` when a pooled connection was reused.
+    pub dns: Option<Duration>,
+    /// TCP connect.
+    pub connect: Option<Duration>,
+    /// `None` for plain http and reused connections.
+    pub tls: Option<Duration>,
+    /// From sending the request to receiving the response headers.
+    pub ttfb: Duration,
+    /// From the response headers to the end of the body.
+    pub download: Duration,
+}
+
+impl Timing {
+    /// Label and duration of every phase that happened, in order.
+    pub fn phases(&self) -> Vec<(&'static str, Duration)> {
+        let mut phases = Vec::new();
+        if !self.redirects.is_zero() {
+            phases.push(("Redirects", self.redirects));
+        }
+        if let Some(dns) = self.dns {
+            phases.push(("DNS", dns));
+        }
+        if let Some(connect) = self.connect {
+            phases.push(("Connect", connect));
+        }
+        if let Some(tls) = self.tls {
+            phases.push(("TLS", tls));
+        }
+        phases.push(("TTFB", self.ttfb));
+        phases.push(("Download", self.download));
+        phases
+    }
+}
+
+/// Phases reported by the resolver and the connector while a request is
+/// being sent.
+#[derive(Debug, Default)]
+struct Recorded {
+    dns: Option<Duration>,
+    /// Includes `dns` and `tls`, the connector resolves the name and does
+    /// the handshake itself.
+    connect: Option<Duration>,
+    /// When the ClientHello was written, see [`TimedSessions`].
+    handshake_started: Option<Instant>,
+    tls: Option<Duration>,
+}
+
+tokio::task_local! {
+    /// Set around `send()` so the hooks below know whose phases they time.
+    /// Connections opened in the background aren't attributed to anyone.
+    static RECORDED: Arc<Mutex<Recorded>>;
+}
+
+/// Shared by every fetch so connections are pooled and the client isn't
+/// rebuilt each time.
+static CLIENT: LazyLock<Result<reqwest::Client, FetchError>> = LazyLock::new(|| {
+    static APP_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+    reqwest::Client::builder()
+        .user_agent(APP_USER_AGENT)
+        .redirect(reqwest::redirect::Policy::none())
+        .dns_resolver(TimedResolver)
+        .connector_layer(tower_layer::layer_fn(TimedConnect))
+        .tls_backend_preconfigured(tls_config()?)
+        .build()
+        .map_err(FetchError::from)
+});
+
+/// What reqwest sets up by default, except for the session cache.
+fn tls_config() -> Result<rustls::ClientConfig, FetchError> {
+    let tls_error = |e: rustls::Error| FetchError::Tls(e.to_string());
+    let provider = Arc::new(rustls::crypto::aws_lc_rs::default_provider());
+    let verifier = rustls_platform_verifier::Verifier::new(provider.clone()).map_err(tls_error)?;
+    let mut config = rustls::ClientConfig::builder_with_provider(provider)
+        .with_safe_default_protocol_versions()
+        .map_err(tls_error)?
+        .dangerous()
+        .with_custom_certificate_verifier(Arc::new(verifier))
+        .with_no_client_auth();
+    config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+    config.resumption = Resumption::store(Arc::new(TimedSessions(ClientSessionMemoryCache::new(
+        SESSIONS,
+    ))));
+    Ok(config)
+}
+
+pub fn client() -> Result<&'static reqwest::Client, FetchError> {
+    CLIENT.as_ref().map_err(Clone::clone)
+}
+
+/// Send a request, timing its phases up to the response headers.
+pub async fn send(
+    request: reqwest::RequestBuilder,
+) -> Result<(reqwest::Response, Timing), FetchError> {
+    let recorded = Arc::new(Mutex::new(Recorded::default()));
+    let sent = Instant::now();
+    let response = RECORDED.scope(recorded.clone(), request.send()).await?;
+    let waited = sent.elapsed();
+
+    let recorded = recorded.lock().unwrap_or_else(|e| e.into_inner());
+    let connect = recorded.connect.unwrap_or_default();
+    let before_tcp = recorded.dns.unwrap_or_default() + recorded.tls.unwrap_or_default();
+    Ok((
+        response,
+        Timing {
+            dns: recorded.dns,
+            connect: recorded
+                .connect
+                .map(|connect| connect.saturating_sub(before_tcp)),
+            tls: recorded.tls,
+            ttfb: waited.saturating_sub(connect),
+            ..Timing::default()
+        },
+    ))
+}
+
+fn record(update: impl FnOnce(&mut Recorded)) -> impl FnOnce() {
+    let recorded = RECORDED.try_with(Arc::clone).ok();
+    move || {
+        if let Some(recorded) = recorded {
+            update(&mut recorded.lock().unwrap_or_else(|e| e.into_inner()));
+        }
+    }
+}
+
+/// The system resolver, timed.
+struct TimedResolver;
+
+impl Resolve for TimedResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let start = Instant::now();
+        let host = name.as_str().to_string();
+        let done = record(move |recorded| recorded.dns = Some(start.elapsed()));
+        Box::pin(async move {
+            let addrs = tokio::net::lookup_host((host, 0))
+                .await
+                .map_err(ResolveError)?;
+            done();
+            Ok(Box::new(addrs) as Addrs)
+        })
+    }
+}
+
+/// A failed lookup, kept as its own type so `FetchError` can tell it apart
+/// from other connect errors.
+#[derive(Debug)]
+pub(crate) struct ResolveError(std::io::Error);
+
+impl std::fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::error::Error for ResolveError {}
+
+/// Wraps reqwest's connector to time connection setup.
+#[derive(Clone)]
+struct TimedConnect<S>(S);
+
+impl<S, Request> Service<Request> for TimedConnect<S>
+where
+    S: Service<Request>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<S::Response, S::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.0.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request) -> Self::Future {
+        let start = Instant::now();
+        let recorded = RECORDED.try_with(Arc::clone).ok();
+        let connecting = self.0.call(request);
+        Box::pin(async move {
+            let Some(recorded) = recorded else {
+                return connecting.await;
+            };
+            // The handshake runs inside the connector, possibly on another
+            // task, so hand the phases to `TimedSessions` while polling it
+            let connection = RECORDED.scope(recorded.clone(), connecting).await?;
+            let mut recorded = recorded.lock().unwrap_or_else(|e| e.into_inner());
+            recorded.connect = Some(start.elapsed());
+            recorded.tls = recorded.handshake_started.map(|started| started.elapsed());
+            Ok(connection)
+        })
+    }
+}
+
+/// Resumable TLS sessions kept per server, rustls' default.
+const SESSIONS: usize = 256;
+
+/// rustls' session cache, noting when a handshake starts: rustls looks for
+/// a session to resume while writing the ClientHello, right after the TCP
+/// connection is up. Neither reqwest nor hyper expose that moment otherwise.
+#[derive(Debug)]
+struct TimedSessions(ClientSessionMemoryCache);
+
+impl TimedSessions {
+    fn handshake_started(&self) {
+        let now = Instant::now();
+        record(move |recorded| {
+            recorded.handshake_started.get_or_insert(now);
+        })();
+    }
+}
+
+impl ClientSessionStore for TimedSessions {
+    fn set_kx_hint(&self, server_name: ServerName<'static>, group: NamedGroup) {
+        self.0.set_kx_hint(server_name, group);
+    }
+
+    fn kx_hint(&self, server_name: &ServerName<'_>) -> Option<NamedGroup> {
+        self.handshake_started();
+        self.0.kx_hint(server_name)
+    }
+
+    fn set_tls12_session(&self, server_name: ServerName<'static>, value: Tls12ClientSessionValue) {
+        self.0.set_tls12_session(server_name, value);
+    }
+
+    fn tls12_session(&self, server_name: &ServerName<'_>) -> Option<Tls12ClientSessionValue> {
+        self.handshake_started();
+        self.0.tls12_session(server_name)
+    }
+
+    fn remove_tls12_session(&self, server_name: &ServerName<'static>) {
+        self.0.remove_tls12_session(server_name);
+    }
+
+    fn insert_tls13_ticket(
+        &self,
+        server_name: ServerName<'static>,
+        value: Tls13ClientSessionValue,
+    ) {
+        self.0.insert_tls13_ticket(server_name, value);
+    }
+
+    fn take_tls13_ticket(
+        &self,
+        server_name: &ServerName<'static>,
+    ) -> Option<Tls13ClientSessionValue> {
+        self.handshake_started();
+        self.0.take_tls13_ticket(server_name)
+    }
+}
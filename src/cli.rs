@@ -0,0 +1,209 @@
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
+use iced::Theme;
+
+pub const USAGE: &str = "\
+Usage: iced-tour [OPTIONS] [DECK_DIR]
+
+Arguments:
+  [DECK_DIR]          Directory containing a deck.ron manifest (default: built-in tour)
+
+Options:
+  --slide <N|NAME>    Start at slide number N (1-based) or at a named slide, e.g. `tasks`
+  --theme <NAME>      Start with the given theme, e.g. `dracula`
+  --fullscreen        Open the audience window in fullscreen
+  --no-terminal       Don't spawn a shell for the terminal slide
+  --session <FILE>    Save progress, quiz answers and demo state to FILE and restore it on start
+  --reset-session     Clear the session file before starting, e.g. for a fresh audience
+  --handout           Print the speaker notes as Markdown and exit
+  --export <DIR>      Render every slide to PNG files and a combined PDF in DIR and exit
+  --poll <ADDR|PORT>  Let attendees vote on quizzes from their phones, e.g. `8080`
+  --offline <DIR>     Serve Page Poker requests from saved pages in DIR, e.g. from `wget -r`
+  -h, --help          Print this help";
+
+/// Parsed command line arguments.
+#[derive(Debug, Clone, Default)]
+pub struct Args {
+    pub deck: Option<PathBuf>,
+    pub slide: Option<String>,
+    pub theme: Option<Theme>,
+    pub fullscreen: bool,
+    pub no_terminal: bool,
+    pub session: Option<PathBuf>,
+    pub reset_session: bool,
+    pub handout: bool,
+    pub export: Option<PathBuf>,
+    pub poll: Option<SocketAddr>,
+    pub offline: Option<PathBuf>,
+    pub help: bool,
+}
+
+impl Args {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut parsed = Args::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--slide" => parsed.slide = Some(value(&arg, args.next())?),
+                "--theme" => parsed.theme = Some(theme(&value(&arg, args.next())?)?),
+                "--fullscreen" => parsed.fullscreen = true,
+                "--no-terminal" => parsed.no_terminal = true,
+                "--session" => parsed.session = Some(PathBuf::from(value(&arg, args.next())?)),
+                "--reset-session" => parsed.reset_session = true,
+                "--handout" => parsed.handout = true,
+                "--export" => parsed.export = Some(PathBuf::from(value(&arg, args.next())?)),
+                "--poll" => parsed.poll = Some(address(&value(&arg, args.next())?)?),
+                "--offline" => parsed.offline = Some(PathBuf::from(value(&arg, args.next())?)),
+                "-h" | "--help" => parsed.help = true,
+                flag if flag.starts_with('-') => return Err(format!("unknown option {flag:?}")),
+                _ if parsed.deck.is_some() => {
+                    return Err(format!("unexpected argument {arg:?}"));
+                }
+                _ => parsed.deck = Some(PathBuf::from(arg)),
+            }
+        }
+
+        if parsed.handout && parsed.export.is_some() {
+            return Err("--handout can't be used with --export".to_string());
+        }
+
+        Ok(parsed)
+    }
+}
+
+fn value(flag: &str, value: Option<String>) -> Result<String, String> {
+    value.ok_or_else(|| format!("{flag} requires a value"))
+}
+
+/// A bare port listens on all interfaces so phones on the same network can connect.
+fn address(value: &str) -> Result<SocketAddr, String> {
+    match value.parse::<u16>() {
+        Ok(port) => Ok(SocketAddr::from(([0, 0, 0, 0], port))),
+        Err(_) => value
+            .parse()
+            .map_err(|_| format!("invalid address {value:?} (expected PORT or IP:PORT)")),
+    }
+}
+
+/// `Gruvbox Light` is accepted as `gruvbox-light`, `gruvboxlight`, `"Gruvbox Light"`, ...
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn theme(name: &str) -> Result<Theme, String> {
+    Theme::ALL
+        .iter()
+        .find(|theme| normalize(&theme.to_string()) == normalize(name))
+        .cloned()
+        .ok_or_else(|| {
+            let valid: Vec<String> = Theme::ALL
+                .iter()
+                .map(|theme| theme.to_string().to_lowercase().replace(' ', "-"))
+                .collect();
+            format!("unknown theme {name:?} (valid: {})", valid.join(", "))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args, String> {
+        Args::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parses_every_option() {
+        let args = parse(&[
+            "talk",
+            "--slide",
+            "tasks",
+            "--theme",
+            "Gruvbox-Light",
+            "--fullscreen",
+            "--no-terminal",
+            "--session",
+            "talk.ron",
+            "--reset-session",
+            "--poll",
+            "8080",
+            "--offline",
+            "site",
+        ])
+        .unwrap();
+
+        assert_eq!(args.deck, Some(PathBuf::from("talk")));
+        assert_eq!(args.slide.as_deref(), Some("tasks"));
+        assert_eq!(args.theme, Some(Theme::GruvboxLight));
+        assert!(args.fullscreen && args.no_terminal && args.reset_session);
+        assert_eq!(args.session, Some(PathBuf::from("talk.ron")));
+        assert_eq!(args.poll, Some(SocketAddr::from(([0, 0, 0, 0], 8080))));
+        assert_eq!(args.offline, Some(PathBuf::from("site")));
+        assert!(!args.handout && !args.help);
+    }
+
+    #[test]
+    fn poll_takes_a_port_or_an_address() {
+        let args = parse(&["--poll", "127.0.0.1:9000"]).unwrap();
+        assert_eq!(args.poll, Some(SocketAddr::from(([127, 0, 0, 1], 9000))));
+
+        let error = parse(&["--poll", "phones"]).unwrap_err();
+        assert!(error.contains("invalid address"), "{error}");
+    }
+
+    #[test]
+    fn unknown_themes_list_the_valid_ones() {
+        let error = parse(&["--theme", "neon"]).unwrap_err();
+        assert!(error.starts_with("unknown theme \"neon\""), "{error}");
+        assert!(
+            error.contains("dracula") && error.contains("gruvbox-light"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn options_need_their_values() {
+        for flag in [
+            "--slide",
+            "--theme",
+            "--session",
+            "--export",
+            "--poll",
+            "--offline",
+        ] {
+            assert_eq!(
+                parse(&[flag]).unwrap_err(),
+                format!("{flag} requires a value")
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_and_extra_arguments() {
+        assert_eq!(
+            parse(&["--slides", "3"]).unwrap_err(),
+            "unknown option \"--slides\""
+        );
+        assert_eq!(
+            parse(&["talk", "other"]).unwrap_err(),
+            "unexpected argument \"other\""
+        );
+    }
+
+    #[test]
+    fn handout_and_export_are_exclusive() {
+        assert!(parse(&["--handout"]).unwrap().handout);
+        assert_eq!(
+            parse(&["--export", "out"]).unwrap().export,
+            Some(PathBuf::from("out"))
+        );
+
+        let error = parse(&["--handout", "--export", "out"]).unwrap_err();
+        assert_eq!(error, "--handout can't be used with --export");
+    }
+}
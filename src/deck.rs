@@ -0,0 +1,481 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use iced::widget::markdown;
+use serde::Deserialize;
+use strum::IntoEnumIterator;
+
+use crate::quiz::{self, Quiz};
+use crate::screen::Screen;
+
+/// Name of the manifest file inside a deck directory.
+pub const MANIFEST: &str = "deck.ron";
+
+/// Talk length assumed when the manifest doesn't specify `minutes`.
+const DEFAULT_MINUTES: u64 = 30;
+
+/// Embedded demos that can be referenced with `Demo(name)`.
+const DEMOS: [(&str, Screen); 2] = [
+    ("page_poker", Screen::Interactive),
+    ("terminal", Screen::CommunityWidgets),
+];
+
+/// The built-in quizzes are shown right before this slide of the tour.
+const QUIZZES_BEFORE: Screen = Screen::Takeaways;
+
+/// An ordered list of slides, either built in or loaded from a deck directory.
+#[derive(Clone)]
+pub struct Deck {
+    pub slides: Vec<Slide>,
+    /// Planned length of the talk, used by the presenter clock.
+    pub duration: Duration,
+}
+
+#[derive(Clone)]
+pub struct Slide {
+    pub title: String,
+    pub notes: String,
+    pub content: SlideContent,
+}
+
+#[derive(Clone)]
+pub enum SlideContent {
+    /// One of the Rust-authored slides in `src/slides/`.
+    Builtin(Screen),
+    /// Markdown (or a code file wrapped in a fenced block) read from the deck.
+    Markdown(Vec<markdown::Item>),
+    /// A multiple-choice question, built in or from the deck's `quizzes.ron`.
+    Quiz(Quiz),
+}
+
+/// On-disk manifest, e.g.:
+///
+/// ```ron
+/// (
+///     minutes: 45,
+///     slides: [
+///         (kind: Builtin("title")),
+///         (title: "Why Iced?", kind: Markdown("why.md"), notes: "Ask who has used Elm."),
+///         (title: "Counter", kind: Code(file: "counter.rs", language: "rust")),
+///         (kind: Quiz("quiz_http")),
+///         (kind: Demo("page_poker")),
+///     ],
+/// )
+/// ```
+#[derive(Deserialize)]
+struct Manifest {
+    #[serde(default)]
+    minutes: Option<u64>,
+    slides: Vec<SlideEntry>,
+}
+
+#[derive(Deserialize)]
+struct SlideEntry {
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default)]
+    notes: String,
+    kind: SlideKind,
+}
+
+#[derive(Deserialize)]
+enum SlideKind {
+    Markdown(PathBuf),
+    Code { file: PathBuf, language: String },
+    Builtin(String),
+    Quiz(String),
+    Demo(String),
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Io(PathBuf, std::io::Error),
+    Manifest(PathBuf, Box<ron::error::SpannedError>),
+    UnknownSlide {
+        kind: &'static str,
+        name: String,
+        valid: Vec<String>,
+    },
+    InvalidQuiz {
+        id: String,
+        reason: &'static str,
+    },
+    Empty,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(path, e) => write!(f, "cannot read {}: {e}", path.display()),
+            Error::Manifest(path, e) => write!(f, "invalid manifest {}: {e}", path.display()),
+            Error::UnknownSlide { kind, name, valid } => {
+                write!(f, "unknown {kind} {name:?} (valid: {})", valid.join(", "))
+            }
+            Error::InvalidQuiz { id, reason } => write!(f, "quiz {id:?} {reason}"),
+            Error::Empty => write!(f, "deck has no slides"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl Deck {
+    /// The compiled-in tour: every `Screen` in declaration order, with the
+    /// built-in quizzes before the recap.
+    pub fn builtin() -> Self {
+        let mut slides: Vec<Slide> = Screen::iter().map(Slide::builtin).collect();
+        let at = slides
+            .iter()
+            .position(|slide| matches!(slide.content, SlideContent::Builtin(QUIZZES_BEFORE)))
+            .unwrap_or(slides.len());
+        slides.splice(at..at, Quiz::builtin().into_iter().map(Slide::quiz));
+
+        Self {
+            slides,
+            duration: Duration::from_secs(DEFAULT_MINUTES * 60),
+        }
+    }
+
+    /// Load a deck from a directory containing a `deck.ron` manifest.
+    pub fn load(dir: impl AsRef<Path>) -> Result<Self, Error> {
+        let dir = dir.as_ref();
+        let manifest_path = dir.join(MANIFEST);
+        let source = read(&manifest_path)?;
+        let manifest: Manifest = ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_str(&source)
+            .map_err(|e| Error::Manifest(manifest_path, Box::new(e)))?;
+
+        // Quizzes from the deck replace built-in ones with the same id
+        let mut quizzes = Quiz::builtin();
+        let quiz_path = dir.join(quiz::FILE);
+        if quiz_path.exists() {
+            for quiz in Quiz::load(&quiz_path)? {
+                quizzes.retain(|existing| existing.id != quiz.id);
+                quizzes.push(quiz);
+            }
+        }
+
+        let slides = manifest
+            .slides
+            .into_iter()
+            .map(|entry| Slide::load(dir, entry, &quizzes))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if slides.is_empty() {
+            return Err(Error::Empty);
+        }
+
+        Ok(Self {
+            slides,
+            duration: Duration::from_secs(manifest.minutes.unwrap_or(DEFAULT_MINUTES) * 60),
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.slides.len()
+    }
+
+    /// Find a slide by 1-based number, by the name of a built-in slide or by
+    /// quiz id.
+    pub fn find(&self, spec: &str) -> Result<usize, Error> {
+        if let Ok(number) = spec.parse::<usize>()
+            && (1..=self.len()).contains(&number)
+        {
+            return Ok(number - 1);
+        }
+
+        let candidates: Vec<(String, usize)> = self
+            .slides
+            .iter()
+            .enumerate()
+            .filter_map(|(i, slide)| match &slide.content {
+                SlideContent::Builtin(screen) => Some((screen.name(), i)),
+                SlideContent::Quiz(quiz) => Some((quiz.id.clone(), i)),
+                SlideContent::Markdown(_) => None,
+            })
+            .collect();
+
+        match candidates.iter().find(|(name, _)| name == spec) {
+            Some((_, index)) => Ok(*index),
+            None => Err(Error::UnknownSlide {
+                kind: "slide",
+                name: spec.to_string(),
+                valid: std::iter::once(format!("1-{}", self.len()))
+                    .chain(candidates.into_iter().map(|(name, _)| name))
+                    .collect(),
+            }),
+        }
+    }
+
+    /// Render every slide's title and speaker notes as a Markdown handout.
+    pub fn handout(&self) -> String {
+        let mut out = String::from("# Speaker Notes\n");
+        for (i, slide) in self.slides.iter().enumerate() {
+            let title = if slide.title.is_empty() {
+                "(untitled)"
+            } else {
+                &slide.title
+            };
+            out.push_str(&format!("\n## Slide {} — {title}\n\n", i + 1));
+            if slide.notes.is_empty() {
+                out.push_str("_No notes._\n");
+            } else {
+                out.push_str(slide.notes.trim());
+                out.push('\n');
+            }
+        }
+        out
+    }
+}
+
+impl Slide {
+    fn builtin(screen: Screen) -> Self {
+        Self {
+            title: screen.to_string(),
+            notes: screen.notes().to_string(),
+            content: SlideContent::Builtin(screen),
+        }
+    }
+
+    fn quiz(quiz: Quiz) -> Self {
+        Self {
+            title: quiz.title.clone(),
+            notes: quiz.notes.trim().to_string(),
+            content: SlideContent::Quiz(quiz),
+        }
+    }
+
+    fn load(dir: &Path, entry: SlideEntry, quizzes: &[Quiz]) -> Result<Self, Error> {
+        let slide = match entry.kind {
+            SlideKind::Builtin(name) => {
+                let candidates = Screen::iter().map(|screen| (screen.name(), screen));
+                Self::builtin(resolve("built-in slide", name, candidates)?)
+            }
+            SlideKind::Quiz(name) => {
+                let candidates = quizzes.iter().map(|quiz| (quiz.id.clone(), quiz.clone()));
+                Self::quiz(resolve("quiz", name, candidates)?)
+            }
+            SlideKind::Demo(name) => {
+                let candidates = DEMOS
+                    .iter()
+                    .map(|(name, screen)| (name.to_string(), *screen));
+                Self::builtin(resolve("demo", name, candidates)?)
+            }
+            SlideKind::Markdown(file) => {
+                let source = read(&dir.join(file))?;
+                Self {
+                    title: String::new(),
+                    notes: String::new(),
+                    content: SlideContent::Markdown(markdown::parse(&source).collect()),
+                }
+            }
+            SlideKind::Code { file, language } => {
+                let source = read(&dir.join(file))?;
+                let fenced = format!("```{language}\n{}\n```", source.trim_end());
+                Self {
+                    title: String::new(),
+                    notes: String::new(),
+                    content: SlideContent::Markdown(markdown::parse(&fenced).collect()),
+                }
+            }
+        };
+
+        // Notes from the manifest replace the built-in ones
+        let slide = if entry.notes.is_empty() {
+            slide
+        } else {
+            Self {
+                notes: entry.notes,
+                ..slide
+            }
+        };
+
+        Ok(match entry.title {
+            Some(title) => Self { title, ..slide },
+            None => slide,
+        })
+    }
+}
+
+fn resolve<T>(
+    kind: &'static str,
+    name: String,
+    candidates: impl Iterator<Item = (String, T)>,
+) -> Result<T, Error> {
+    let mut candidates: Vec<(String, T)> = candidates.collect();
+    match candidates
+        .iter()
+        .position(|(candidate, _)| *candidate == name)
+    {
+        Some(index) => Ok(candidates.swap_remove(index).1),
+        None => Err(Error::UnknownSlide {
+            kind,
+            name,
+            valid: candidates.into_iter().map(|(name, _)| name).collect(),
+        }),
+    }
+}
+
+fn read(path: &Path) -> Result<String, Error> {
+    std::fs::read_to_string(path).map_err(|e| Error::Io(path.to_path_buf(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A deck directory holding `files`, e.g. `("deck.ron", "...")`.
+    fn deck_dir(files: &[(&str, &str)]) -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        for (name, contents) in files {
+            std::fs::write(dir.path().join(name), contents).unwrap();
+        }
+        dir
+    }
+
+    fn load(manifest: &str) -> Result<Deck, Error> {
+        Deck::load(deck_dir(&[(MANIFEST, manifest)]).path())
+    }
+
+    #[test]
+    fn loads_every_kind_of_slide() {
+        let dir = deck_dir(&[
+            (
+                MANIFEST,
+                r#"(
+                    minutes: 45,
+                    slides: [
+                        (kind: Builtin("title")),
+                        (title: "Why Iced?", kind: Markdown("why.md"), notes: "Ask around."),
+                        (title: "Counter", kind: Code(file: "counter.rs", language: "rust")),
+                        (kind: Quiz("quiz_http")),
+                        (kind: Demo("page_poker")),
+                    ],
+                )"#,
+            ),
+            ("why.md", "# Why"),
+            ("counter.rs", "fn main() {}"),
+        ]);
+
+        let deck = Deck::load(dir.path()).unwrap();
+        assert_eq!(deck.duration, Duration::from_secs(45 * 60));
+        let titles: Vec<&str> = deck
+            .slides
+            .iter()
+            .map(|slide| slide.title.as_str())
+            .collect();
+        assert_eq!(titles[1..3], ["Why Iced?", "Counter"]);
+        assert_eq!(deck.slides[1].notes, "Ask around.");
+        assert!(matches!(
+            deck.slides[0].content,
+            SlideContent::Builtin(Screen::Title)
+        ));
+        assert!(
+            matches!(&deck.slides[3].content, SlideContent::Quiz(quiz) if quiz.id == "quiz_http")
+        );
+        assert!(matches!(
+            deck.slides[4].content,
+            SlideContent::Builtin(Screen::Interactive)
+        ));
+    }
+
+    #[test]
+    fn minutes_default_to_a_half_hour_talk() {
+        let deck = load(r#"(slides: [(kind: Builtin("title"))])"#).unwrap();
+        assert_eq!(deck.duration, Duration::from_secs(DEFAULT_MINUTES * 60));
+    }
+
+    #[test]
+    fn unknown_names_list_the_valid_ones() {
+        for (manifest, expected) in [
+            (r#"(slides: [(kind: Builtin("nope"))])"#, "built-in slide"),
+            (r#"(slides: [(kind: Quiz("nope"))])"#, "quiz"),
+            (r#"(slides: [(kind: Demo("nope"))])"#, "demo"),
+        ] {
+            match load(manifest) {
+                Err(Error::UnknownSlide { kind, name, valid }) => {
+                    assert_eq!(kind, expected);
+                    assert_eq!(name, "nope");
+                    assert!(!valid.is_empty());
+                }
+                other => panic!("expected an unknown {expected}, got {:?}", other.err()),
+            }
+        }
+    }
+
+    #[test]
+    fn broken_decks_are_rejected() {
+        assert!(matches!(load("(slides: [])"), Err(Error::Empty)));
+        assert!(matches!(load("(slides: ["), Err(Error::Manifest(..))));
+        assert!(matches!(
+            load(r#"(slides: [(kind: Markdown("missing.md"))])"#),
+            Err(Error::Io(..))
+        ));
+        assert!(matches!(
+            Deck::load(tempfile::tempdir().unwrap().path()),
+            Err(Error::Io(..))
+        ));
+    }
+
+    #[test]
+    fn deck_quizzes_replace_builtin_ones() {
+        let dir = deck_dir(&[
+            (MANIFEST, r#"(slides: [(kind: Quiz("quiz_http"))])"#),
+            (
+                quiz::FILE,
+                r#"[(
+                    id: "quiz_http",
+                    title: "Mine",
+                    question: "?",
+                    options: [(label: "Yes", correct: true, explanation: "")],
+                )]"#,
+            ),
+        ]);
+
+        let deck = Deck::load(dir.path()).unwrap();
+        assert_eq!(deck.slides[0].title, "Mine");
+    }
+
+    #[test]
+    fn quiz_ids_are_unique() {
+        let quiz = r#"(
+            id: "twice",
+            title: "Twice",
+            question: "?",
+            options: [(label: "Yes", correct: true, explanation: "")],
+        )"#;
+        let dir = deck_dir(&[
+            (MANIFEST, r#"(slides: [(kind: Quiz("twice"))])"#),
+            (quiz::FILE, &format!("[{quiz}, {quiz}]")),
+        ]);
+
+        match Deck::load(dir.path()) {
+            Err(Error::InvalidQuiz { id, reason }) => {
+                assert_eq!(id, "twice");
+                assert_eq!(reason, "is defined more than once");
+            }
+            other => panic!("expected a duplicate quiz, got {:?}", other.err()),
+        }
+    }
+
+    #[test]
+    fn finds_slides_by_number_or_name() {
+        let deck = Deck::builtin();
+        assert_eq!(deck.find("1").unwrap(), 0);
+        assert_eq!(deck.find("title").unwrap(), 0);
+        let quiz = deck.find("quiz_http").unwrap();
+        assert!(
+            matches!(&deck.slides[quiz].content, SlideContent::Quiz(quiz) if quiz.id == "quiz_http")
+        );
+
+        let past_the_end = (deck.len() + 1).to_string();
+        for spec in ["0", past_the_end.as_str(), "nope"] {
+            assert!(
+                matches!(deck.find(spec), Err(Error::UnknownSlide { .. })),
+                "{spec}"
+            );
+        }
+    }
+}
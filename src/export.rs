@@ -0,0 +1,189 @@
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::Path;
+
+use iced::advanced::renderer::{self, Headless};
+use iced::theme::Base;
+use iced::widget::image;
+use iced::{Size, mouse};
+use iced_anim::{Animated, Motion};
+use iced_runtime::user_interface::{Cache, UserInterface};
+use miniz_oxide::deflate::{CompressionLevel, compress_to_vec_zlib};
+use pdf_writer::{Content, Filter, Finish, Name, Pdf, Rect, Ref};
+
+use crate::sliding::SlideOffset;
+use crate::{App, BITTER, FONTS, TEXT_SIZE};
+
+/// Logical size every slide is laid out at; matches the 1024×768 base of `App::scale`.
+const SLIDE_SIZE: Size = Size::new(1024.0, 768.0);
+
+/// Pixel density of the exported images.
+const SCALE_FACTOR: f32 = 2.0;
+
+/// Pixel density of the previews in the overview and the presenter window.
+const PREVIEW_SCALE_FACTOR: f32 = 0.5;
+
+/// A rendered slide in RGBA, `SLIDE_SIZE` times the scale factor large.
+struct Page {
+    size: Size<u32>,
+    rgba: Vec<u8>,
+}
+
+/// Render every slide through the software renderer and write `slide-NN.png`
+/// files plus a combined `slides.pdf` into `dir`.
+pub fn export(app: &mut App, dir: &Path) -> Result<(), String> {
+    let mut renderer = renderer()?;
+
+    std::fs::create_dir_all(dir).map_err(|e| format!("{}: {e}", dir.display()))?;
+
+    app.canvas_size = (SLIDE_SIZE.width, SLIDE_SIZE.height);
+
+    let mut pages = Vec::with_capacity(app.deck.len());
+    for index in 0..app.deck.len() {
+        app.current = index;
+        let page = render(app, &mut renderer, SCALE_FACTOR);
+
+        let path = dir.join(format!("slide-{:02}.png", index + 1));
+        write_png(&path, &page).map_err(|e| format!("{}: {e}", path.display()))?;
+        pages.push(page);
+    }
+
+    let path = dir.join("slides.pdf");
+    std::fs::write(&path, pdf(&pages)).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// A software renderer with the app's fonts loaded, independent of any window.
+pub fn renderer() -> Result<iced::Renderer, String> {
+    for font in FONTS {
+        iced::advanced::graphics::text::font_system()
+            .write()
+            .expect("font system lock poisoned")
+            .load_font(font.into());
+    }
+
+    iced::futures::executor::block_on(iced::Renderer::new(
+        BITTER,
+        TEXT_SIZE.into(),
+        Some("tiny-skia"),
+    ))
+    .ok_or_else(|| "software renderer unavailable".to_string())
+}
+
+/// Render slide `index` offscreen, without switching to it, so it can be
+/// previewed before it has ever been shown.
+pub fn preview(app: &mut App, renderer: &mut iced::Renderer, index: usize) -> image::Handle {
+    // Draw it settled, at the export size and without the overlays
+    let current = std::mem::replace(&mut app.current, index);
+    let canvas_size =
+        std::mem::replace(&mut app.canvas_size, (SLIDE_SIZE.width, SLIDE_SIZE.height));
+    let show_overview = std::mem::take(&mut app.show_overview);
+    let show_notes = std::mem::take(&mut app.show_notes);
+    let slide_offset = std::mem::replace(
+        &mut app.slide_offset,
+        Animated::new(SlideOffset::settled(), Motion::SNAPPY),
+    );
+
+    let page = render(app, renderer, PREVIEW_SCALE_FACTOR);
+
+    app.current = current;
+    app.canvas_size = canvas_size;
+    app.show_overview = show_overview;
+    app.show_notes = show_notes;
+    app.slide_offset = slide_offset;
+
+    image::Handle::from_rgba(page.size.width, page.size.height, page.rgba)
+}
+
+/// Lay out and draw the audience view exactly like the live app does.
+fn render(app: &App, renderer: &mut iced::Renderer, scale_factor: f32) -> Page {
+    let theme = app.theme.clone();
+    let base = theme.base();
+
+    let mut ui = UserInterface::build(
+        app.view(app.main_window),
+        SLIDE_SIZE,
+        Cache::default(),
+        renderer,
+    );
+    ui.draw(
+        renderer,
+        &theme,
+        &renderer::Style {
+            text_color: base.text_color,
+        },
+        mouse::Cursor::Unavailable,
+    );
+
+    let size = Size::new(
+        (SLIDE_SIZE.width * scale_factor) as u32,
+        (SLIDE_SIZE.height * scale_factor) as u32,
+    );
+    let rgba = renderer.screenshot(size, scale_factor, base.background_color);
+
+    Page { size, rgba }
+}
+
+fn write_png(path: &Path, page: &Page) -> Result<(), png::EncodingError> {
+    let file = BufWriter::new(File::create(path)?);
+    let mut encoder = png::Encoder::new(file, page.size.width, page.size.height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.write_header()?.write_image_data(&page.rgba)
+}
+
+/// One full-bleed image per page, with pages sized like the slides (in points).
+fn pdf(pages: &[Page]) -> Vec<u8> {
+    let mut pdf = Pdf::new();
+    let catalog_id = Ref::new(1);
+    let page_tree_id = Ref::new(2);
+
+    // Each page needs three objects: the page, its image and its content stream
+    let ids = |index: usize| {
+        let base = 3 + 3 * index as i32;
+        (Ref::new(base), Ref::new(base + 1), Ref::new(base + 2))
+    };
+
+    pdf.catalog(catalog_id).pages(page_tree_id);
+    pdf.pages(page_tree_id)
+        .kids((0..pages.len()).map(|i| ids(i).0))
+        .count(pages.len() as i32);
+
+    let image_name = Name(b"Slide");
+    let media_box = Rect::new(0.0, 0.0, SLIDE_SIZE.width, SLIDE_SIZE.height);
+
+    for (index, page) in pages.iter().enumerate() {
+        let (page_id, image_id, content_id) = ids(index);
+
+        let mut pdf_page = pdf.page(page_id);
+        pdf_page.media_box(media_box);
+        pdf_page.parent(page_tree_id);
+        pdf_page.contents(content_id);
+        pdf_page.resources().x_objects().pair(image_name, image_id);
+        pdf_page.finish();
+
+        let rgb: Vec<u8> = page
+            .rgba
+            .chunks_exact(4)
+            .flat_map(|pixel| &pixel[..3])
+            .copied()
+            .collect();
+        let encoded = compress_to_vec_zlib(&rgb, CompressionLevel::DefaultLevel as u8);
+
+        let mut image = pdf.image_xobject(image_id, &encoded);
+        image.filter(Filter::FlateDecode);
+        image.width(page.size.width as i32);
+        image.height(page.size.height as i32);
+        image.color_space().device_rgb();
+        image.bits_per_component(8);
+        image.finish();
+
+        let mut content = Content::new();
+        content.save_state();
+        content.transform([SLIDE_SIZE.width, 0.0, 0.0, SLIDE_SIZE.height, 0.0, 0.0]);
+        content.x_object(image_name);
+        content.restore_state();
+        pdf.stream(content_id, &content.finish());
+    }
+
+    pdf.finish()
+}
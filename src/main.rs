@@ -1,21 +1,37 @@
+use std::collections::{BTreeMap, HashMap};
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
 use iced::{
     Color, Element, Event, Font, Padding, Subscription, Task, Theme, event, keyboard,
     widget::{
-        canvas, column, container, markdown, pick_list, row, space, stack, text, themer,
+        canvas, column, container, image, markdown, pick_list, row, scrollable, space, stack,
+        text, themer,
     },
+    window,
 };
 use iced_anim::{Animated, Animation, Motion, widget::button};
 use lucide_icons::{
     LUCIDE_FONT_BYTES,
     iced::{icon_chevron_left, icon_chevron_right},
 };
-use strum::EnumCount;
 
+use crate::deck::{Deck, SlideContent};
 use crate::screen::Screen;
 use theme::AppTheme;
 
 mod chaos;
+mod cli;
+mod deck;
+mod export;
+mod overview;
+mod poll;
+mod presenter;
+mod quiz;
+mod score;
 mod screen;
+mod session;
 mod slides;
 mod sliding;
 mod theme;
@@ -34,8 +50,29 @@ pub const INCORRECT_COLOR: Color = Color::from_rgb(0.85, 0.25, 0.25);
 
 pub const ELM_CIRCLE_OF_LIFE: &[u8] = include_bytes!("../assets/elm.svg");
 
+const FONTS: [&[u8]; 4] = [
+    LUCIDE_FONT_BYTES,
+    iced_aw::ICED_AW_FONT_BYTES,
+    include_bytes!("../fonts/Bitter-Regular.ttf"),
+    include_bytes!("../fonts/FiraMono-Regular.ttf"),
+];
+
 pub struct App {
-    pub screen: Screen,
+    pub deck: Deck,
+    pub current: usize,
+    main_window: window::Id,
+    presenter: Option<presenter::Presenter>,
+    pub show_notes: bool,
+    session: Option<session::SessionFile>,
+    pub show_overview: bool,
+    /// Slide number typed while the overview is open.
+    pub jump_input: String,
+    /// Offscreen renders of slides, keyed by slide index.
+    thumbnails: HashMap<usize, image::Handle>,
+    /// Draws the thumbnails, created when the first one is needed.
+    preview_renderer: Option<Result<iced::Renderer, String>>,
+    /// A `RenderPreview` is on its way, so another one isn't queued.
+    rendering_preview: bool,
     slide_offset: Animated<sliding::SlideOffset>,
     pub page_poker: page_poker::PagePoker,
     pub theme: Theme,
@@ -52,11 +89,21 @@ pub struct App {
     pub demo_padding: f32,
     pub hover_color: Color,
     pub show_color_picker: bool,
-    pub quiz_answer: Option<u8>,
-    pub quiz_http_answer: Option<u8>,
-    pub quiz_button_answer: Option<u8>,
-    pub quiz_validation_answer: Option<u8>,
-    pub term: iced_term::Terminal,
+    /// Options picked so far, in order, keyed by quiz id.
+    pub quiz_answers: BTreeMap<String, Vec<usize>>,
+    /// Outcome of the last results export, shown on the results slide.
+    pub results_export: Option<Result<PathBuf, String>>,
+    /// Address to run the audience polling server on, from `--poll`.
+    poll_addr: Option<SocketAddr>,
+    /// Set once the polling server is up.
+    pub poll: Option<poll::Server>,
+    pub poll_error: Option<String>,
+    /// Attendee votes keyed by quiz id, then by voter.
+    pub poll_votes: BTreeMap<String, HashMap<String, usize>>,
+    /// Fixture directory Page Poker is served from, from `--offline`.
+    offline: Option<PathBuf>,
+    /// `None` when started with `--no-terminal`.
+    pub term: Option<iced_term::Terminal>,
 
     // Cached markdown content for each screen
     pub md_intro: Vec<markdown::Item>,
@@ -75,18 +122,29 @@ pub struct App {
     pub md_widget_messages: Vec<markdown::Item>,
 }
 
-impl Default for App {
-    fn default() -> Self {
+impl App {
+    fn new(deck: Deck, start: Option<usize>, args: &cli::Args) -> (Self, Task<Message>) {
         use slides::*;
 
-        Self {
-            screen: Screen::default(),
+        let (main_window, open) = window::open(window::Settings {
+            fullscreen: args.fullscreen,
+            ..Default::default()
+        });
+
+        let mut app = Self {
+            deck,
+            current: 0,
+            main_window,
+            presenter: None,
+            show_notes: false,
+            session: None,
+            show_overview: false,
+            jump_input: String::new(),
+            thumbnails: HashMap::new(),
+            preview_renderer: None,
+            rendering_preview: false,
             slide_offset: Animated::new(sliding::SlideOffset::settled(), Motion::SNAPPY),
-            page_poker: page_poker::PagePoker::with_style(page_poker::StyleConfig {
-                mono_font: FIRA_MONO,
-                subtitle_color: SUBTITLE_COLOR,
-                text_size: TEXT_SIZE,
-            }),
+            page_poker: styled_page_poker(args.offline.as_deref()),
             theme: Theme::GruvboxLight,
             ctrl_held: false,
             shift_held: false,
@@ -101,11 +159,14 @@ impl Default for App {
             demo_padding: 10.0,
             hover_color: Color::from_rgb(0.3, 0.7, 1.0),
             show_color_picker: false,
-            quiz_answer: None,
-            quiz_http_answer: None,
-            quiz_button_answer: None,
-            quiz_validation_answer: None,
-            term: shell_backend(),
+            quiz_answers: BTreeMap::new(),
+            results_export: None,
+            poll_addr: args.poll,
+            poll: None,
+            poll_error: None,
+            poll_votes: BTreeMap::new(),
+            offline: args.offline.clone(),
+            term: (!args.no_terminal).then(shell_backend),
             md_intro: markdown::parse(intro::MD_INTRO).collect(),
             md_model: markdown::parse(model::MD_MODEL).collect(),
             md_view: markdown::parse(view::MD_VIEW).collect(),
@@ -120,7 +181,45 @@ impl Default for App {
             md_subscriptions: markdown::parse(subscriptions::MD_SUBSCRIPTIONS).collect(),
             md_constructors: markdown::parse(constructors::MD_CONSTRUCTORS).collect(),
             md_widget_messages: markdown::parse(constructors::MD_WIDGET_MESSAGES).collect(),
+        };
+
+        if let Some(path) = &args.session {
+            let restored = session::Session::load(path).unwrap_or_else(|e| {
+                eprintln!("warning: ignoring session file {e}");
+                session::Session::default()
+            });
+            restored.clone().apply(&mut app);
+            app.session = Some(session::SessionFile {
+                path: path.clone(),
+                saved: restored,
+                saving: false,
+            });
+        }
+
+        // Explicit arguments win over the restored session
+        if let Some(start) = start {
+            app.current = start;
+        }
+        if let Some(theme) = &args.theme {
+            app.theme = theme.clone();
         }
+
+        (app, open.discard())
+    }
+}
+
+fn styled_page_poker(offline: Option<&Path>) -> page_poker::PagePoker {
+    let style = page_poker::StyleConfig {
+        mono_font: FIRA_MONO,
+        subtitle_color: SUBTITLE_COLOR,
+        error_color: INCORRECT_COLOR,
+        accent_color: ORANGE,
+        highlight_theme: iced::highlighter::Theme::InspiredGitHub,
+        text_size: TEXT_SIZE,
+    };
+    match offline {
+        Some(dir) => page_poker::PagePoker::with_fetcher(style, page_poker::Fixtures::dir(dir)),
+        None => page_poker::PagePoker::with_style(style),
     }
 }
 
@@ -149,6 +248,23 @@ pub enum Message {
     // Navigation
     NextScreen,
     PrevScreen,
+    ToggleOverview,
+    JumpTo(usize),
+    JumpDigit(u32),
+    JumpBackspace,
+    JumpSubmit,
+
+    // Presenter window
+    TogglePresenter,
+    ToggleNotes,
+    ClockTick(Instant),
+    ResetClock,
+    RenderPreview,
+    WindowClosed(window::Id),
+
+    // Session
+    ResetSession,
+    SessionSaved(Result<(), String>),
 
     // Page Poker (interactive screen)
     PagePoker(page_poker::Message),
@@ -180,34 +296,143 @@ pub enum Message {
     SpawnChaos,
     PanicChaos,
     Tick,
-    WindowResized(f32, f32),
+    WindowResized(window::Id, f32, f32),
 
     // Quiz
-    QuizAnswer(u8),
-    QuizHttpAnswer(u8),
-    QuizButtonAnswer(u8),
-    QuizValidationAnswer(u8),
+    QuizAnswer(String, usize),
+    ExportResults(score::Format),
+    ResultsExported(Result<PathBuf, String>),
+    Poll(poll::Event),
 
     // Terminal
     TermEvent(iced_term::Event),
 }
 
 fn main() -> iced::Result {
-    iced::application(App::default, App::update, App::view)
-        .title("Iced Tutorial")
-        .theme(App::theme)
-        .subscription(App::subscription)
-        .antialiasing(true)
-        .font(LUCIDE_FONT_BYTES)
-        .font(iced_aw::ICED_AW_FONT_BYTES)
-        .font(include_bytes!("../fonts/Bitter-Regular.ttf"))
-        .font(include_bytes!("../fonts/FiraMono-Regular.ttf"))
-        .default_font(BITTER)
+    let args = match cli::Args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => exit_with(&format!("{e}\n\n{}", cli::USAGE)),
+    };
+
+    if args.help {
+        println!("{}", cli::USAGE);
+        return Ok(());
+    }
+
+    // An optional deck directory replaces the built-in tour.
+    let deck = match &args.deck {
+        Some(dir) => Deck::load(dir).unwrap_or_else(|e| exit_with(&e.to_string())),
+        None => Deck::builtin(),
+    };
+
+    if args.handout {
+        print!("{}", deck.handout());
+        return Ok(());
+    }
+
+    let start = args
+        .slide
+        .as_ref()
+        .map(|spec| deck.find(spec).unwrap_or_else(|e| exit_with(&e.to_string())));
+
+    if args.reset_session
+        && let Some(path) = &args.session
+        && let Err(e) = session::remove(path)
+    {
+        exit_with(&e);
+    }
+
+    if let Some(dir) = &args.export {
+        let args = cli::Args {
+            no_terminal: true,
+            ..args.clone()
+        };
+        let (mut app, _) = App::new(deck, start, &args);
+        export::export(&mut app, dir).unwrap_or_else(|e| exit_with(&e));
+        return Ok(());
+    }
+
+    let daemon = iced::daemon(
+        move || App::new(deck.clone(), start, &args),
+        App::update,
+        App::view,
+    )
+    .title(App::title)
+    .theme(App::theme)
+    .subscription(App::subscription)
+    .antialiasing(true)
+    .default_font(BITTER);
+
+    FONTS
+        .into_iter()
+        .fold(daemon, |daemon, font| daemon.font(font))
         .run()
 }
 
+impl Message {
+    /// Whether handling it can change what a [`session::Session`] keeps.
+    /// Ticks arrive up to 60 times a second and are not worth a snapshot.
+    fn changes_session(&self) -> bool {
+        !matches!(
+            self,
+            Message::Noop
+                | Message::ClockTick(_)
+                | Message::RenderPreview
+                | Message::SessionSaved(_)
+                | Message::WindowClosed(_)
+                | Message::SlideOffset(_)
+                | Message::CtrlPressed
+                | Message::CtrlReleased
+                | Message::ShiftPressed
+                | Message::ShiftReleased
+                | Message::SpawnChaos
+                | Message::PanicChaos
+                | Message::Tick
+                | Message::WindowResized(..)
+                | Message::Poll(_)
+                | Message::TermEvent(_)
+                | Message::PagePoker(page_poker::Message::Progress(_))
+        )
+    }
+}
+
+fn exit_with(message: &str) -> ! {
+    eprintln!("error: {message}");
+    std::process::exit(2);
+}
+
 impl App {
-    fn theme(&self) -> Theme {
+    /// The Rust-authored slide currently shown, if any.
+    pub fn screen(&self) -> Option<Screen> {
+        match self.deck.slides[self.current].content {
+            SlideContent::Builtin(screen) => Some(screen),
+            SlideContent::Markdown(_) | SlideContent::Quiz(_) => None,
+        }
+    }
+
+    pub fn is_first(&self) -> bool {
+        self.current == 0
+    }
+
+    pub fn is_last(&self) -> bool {
+        self.current + 1 == self.deck.len()
+    }
+
+    fn title(&self, window: window::Id) -> String {
+        if self.is_presenter(window) {
+            "Iced Tutorial — Presenter".to_string()
+        } else {
+            "Iced Tutorial".to_string()
+        }
+    }
+
+    fn is_presenter(&self, window: window::Id) -> bool {
+        self.presenter
+            .as_ref()
+            .is_some_and(|presenter| presenter.window == window)
+    }
+
+    fn theme(&self, _window: window::Id) -> Theme {
         self.theme.clone()
     }
 
@@ -215,7 +440,7 @@ impl App {
         use keyboard::Key;
         use keyboard::key::Named;
 
-        let events = event::listen_with(|event, _status, _id| match event {
+        let events = event::listen_with(|event, status, id| match event {
             Event::Keyboard(keyboard::Event::KeyPressed {
                 key: Key::Named(Named::Control),
                 ..
@@ -240,66 +465,268 @@ impl App {
                 key: Key::Named(Named::ArrowRight),
                 ..
             }) => Some(Message::NextScreen),
-            Event::Window(iced::window::Event::Resized(size)) => {
-                Some(Message::WindowResized(size.width, size.height))
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: Key::Character(c),
+                ..
+            }) if c.as_str() == "p" && status == event::Status::Ignored => {
+                Some(Message::TogglePresenter)
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: Key::Character(c),
+                ..
+            }) if c.as_str() == "n" && status == event::Status::Ignored => {
+                Some(Message::ToggleNotes)
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: Key::Character(c),
+                ..
+            }) if c.as_str() == "o" && status == event::Status::Ignored => {
+                Some(Message::ToggleOverview)
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: Key::Named(Named::Escape),
+                ..
+            }) if status == event::Status::Ignored => Some(Message::ToggleOverview),
+            Event::Window(window::Event::Resized(size)) => {
+                Some(Message::WindowResized(id, size.width, size.height))
             }
             _ => None,
         });
 
-        let needs_tick = self.screen == Screen::Subscriptions
+        let needs_tick = self.screen() == Some(Screen::Subscriptions)
             || self.slide_offset.value() != &sliding::SlideOffset::settled();
 
-        let term_sub = self.term.subscription().map(Message::TermEvent);
+        let term_sub = match &self.term {
+            Some(term) => term.subscription().map(Message::TermEvent),
+            None => Subscription::none(),
+        };
+        let closed = window::close_events().map(Message::WindowClosed);
+        let clock = if self.presenter.is_some() {
+            iced::time::every(std::time::Duration::from_secs(1)).map(Message::ClockTick)
+        } else {
+            Subscription::none()
+        };
+        let jump = if self.show_overview {
+            event::listen_with(overview::jump_keys)
+        } else {
+            Subscription::none()
+        };
+        // Ctrl+R is also reverse search in the terminal, so only take it when
+        // nothing else did and there is a session to reset
+        let reset = if self.session.is_some() {
+            event::listen_with(|event, status, _| match event {
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key: Key::Character(c),
+                    modifiers,
+                    ..
+                }) if c.as_str() == "r"
+                    && modifiers.command()
+                    && status == event::Status::Ignored =>
+                {
+                    Some(Message::ResetSession)
+                }
+                _ => None,
+            })
+        } else {
+            Subscription::none()
+        };
+        let polling = match self.poll_addr {
+            Some(addr) => poll::subscription(addr).map(Message::Poll),
+            None => Subscription::none(),
+        };
 
-        if self.screen == Screen::Subscriptions {
+        if self.screen() == Some(Screen::Subscriptions) {
             let tick =
                 iced::time::every(std::time::Duration::from_millis(16)).map(|_| Message::Tick);
             let spawn_timer =
                 iced::time::every(std::time::Duration::from_secs(3)).map(|_| Message::SpawnChaos);
-            Subscription::batch([events, tick, spawn_timer, term_sub])
+            Subscription::batch([
+                events,
+                tick,
+                spawn_timer,
+                term_sub,
+                closed,
+                clock,
+                jump,
+                reset,
+                polling,
+            ])
         } else if needs_tick {
             let tick =
                 iced::time::every(std::time::Duration::from_millis(16)).map(|_| Message::Tick);
-            Subscription::batch([events, tick, term_sub])
+            Subscription::batch([events, tick, term_sub, closed, clock, jump, reset, polling])
         } else {
-            Subscription::batch([events, term_sub])
+            Subscription::batch([events, term_sub, closed, clock, jump, reset, polling])
         }
     }
 
     fn update(&mut self, message: Message) -> Task<Message> {
+        // A finished save may have skipped changes made while it ran
+        let saved = matches!(message, Message::SessionSaved(_));
+        let changes_session = saved || message.changes_session();
+        let task = self.handle(message);
+
+        // Keep the attendees' phones on the quiz that is on screen
+        if let Some(server) = &self.poll {
+            let question = match &self.deck.slides[self.current].content {
+                SlideContent::Quiz(quiz) => Some(poll::Question {
+                    quiz: quiz.id.clone(),
+                    question: quiz.question.clone(),
+                    options: quiz.options.iter().map(|choice| choice.label.clone()).collect(),
+                }),
+                _ => None,
+            };
+            server.publish(question);
+        }
+
+        // Render the previews about to be looked at one per message, so the
+        // windows stay responsive while they come in
+        let task = if !self.rendering_preview && self.missing_preview().is_some() {
+            self.rendering_preview = true;
+            Task::batch([task, Task::done(Message::RenderPreview)])
+        } else {
+            task
+        };
+
+        // Write the session file whenever something worth restoring changed
+        if let Some(file) = &mut self.session
+            && saved
+        {
+            file.saving = false;
+        }
+        match &self.session {
+            Some(file) if !file.saving && changes_session => {}
+            _ => return task,
+        }
+        let current = session::Session::capture(self);
+        match &mut self.session {
+            Some(file) if file.saved != current => {
+                file.saved = current.clone();
+                file.saving = true;
+                let save = Task::perform(current.save(file.path.clone()), Message::SessionSaved);
+                Task::batch([task, save])
+            }
+            _ => task,
+        }
+    }
+
+    fn handle(&mut self, message: Message) -> Task<Message> {
         match message {
             Message::Noop => Task::none(),
 
             // Navigation
             Message::NextScreen => {
-                if !self.screen.is_last() {
-                    self.chaos_circles.clear();
-                    self.chaos_paused = false;
-                    self.screen.forward();
-                    self.slide_offset =
-                        Animated::new(sliding::SlideOffset::entering_forward(), Motion::SNAPPY);
-                    self.slide_offset
-                        .set_target(sliding::SlideOffset::settled());
+                if !self.is_last() {
+                    self.go_to(self.current + 1);
                 }
                 Task::none()
             }
             Message::PrevScreen => {
-                if !self.screen.is_first() {
-                    self.chaos_circles.clear();
-                    self.chaos_paused = false;
-                    self.screen.backward();
-                    self.slide_offset =
-                        Animated::new(sliding::SlideOffset::entering_backward(), Motion::SNAPPY);
-                    self.slide_offset
-                        .set_target(sliding::SlideOffset::settled());
+                if !self.is_first() {
+                    self.go_to(self.current - 1);
                 }
                 Task::none()
             }
+            Message::ToggleOverview => {
+                self.show_overview = !self.show_overview;
+                self.jump_input.clear();
+                Task::none()
+            }
+            Message::JumpTo(index) => {
+                self.show_overview = false;
+                self.jump_input.clear();
+                self.go_to(index);
+                Task::none()
+            }
+            Message::JumpDigit(digit) => {
+                self.jump_input.push_str(&digit.to_string());
+                Task::none()
+            }
+            Message::JumpBackspace => {
+                self.jump_input.pop();
+                Task::none()
+            }
+            Message::JumpSubmit => match self.jump_input.parse::<usize>() {
+                Ok(number) if (1..=self.deck.len()).contains(&number) => {
+                    self.handle(Message::JumpTo(number - 1))
+                }
+                _ => {
+                    self.jump_input.clear();
+                    Task::none()
+                }
+            },
             Message::SlideOffset(event) => {
                 self.slide_offset.update(event);
                 Task::none()
             }
 
+            // Presenter window
+            Message::TogglePresenter => match self.presenter.take() {
+                Some(presenter) => window::close(presenter.window),
+                None => {
+                    let (id, open) = window::open(window::Settings::default());
+                    self.presenter = Some(presenter::Presenter::new(id));
+                    open.discard()
+                }
+            },
+            Message::ToggleNotes => {
+                self.show_notes = !self.show_notes;
+                Task::none()
+            }
+            Message::ClockTick(now) => {
+                if let Some(presenter) = &mut self.presenter {
+                    presenter.now = now;
+                }
+                Task::none()
+            }
+            Message::ResetClock => {
+                if let Some(presenter) = &mut self.presenter {
+                    presenter.started = presenter.now;
+                }
+                Task::none()
+            }
+            Message::RenderPreview => {
+                self.rendering_preview = false;
+                let Some(index) = self.missing_preview() else {
+                    return Task::none();
+                };
+                match self.preview_renderer.take().unwrap_or_else(export::renderer) {
+                    Ok(mut renderer) => {
+                        let handle = export::preview(self, &mut renderer, index);
+                        self.thumbnails.insert(index, handle);
+                        self.preview_renderer = Some(Ok(renderer));
+                    }
+                    Err(e) => {
+                        eprintln!("warning: can't render slide previews: {e}");
+                        self.preview_renderer = Some(Err(e));
+                    }
+                }
+                Task::none()
+            }
+            Message::ResetSession => {
+                session::Session::default().apply(self);
+                self.page_poker = styled_page_poker(self.offline.as_deref());
+                self.poll_votes.clear();
+                self.thumbnails.clear();
+                Task::none()
+            }
+            Message::SessionSaved(result) => {
+                if let Err(e) = result {
+                    eprintln!("warning: failed to save session: {e}");
+                }
+                Task::none()
+            }
+            Message::WindowClosed(id) => {
+                if id == self.main_window {
+                    iced::exit()
+                } else {
+                    if self.is_presenter(id) {
+                        self.presenter = None;
+                    }
+                    Task::none()
+                }
+            }
+
             // Page Poker
             Message::PagePoker(msg) => self.page_poker.update(msg).map(Message::PagePoker),
             Message::ButtonClicked => {
@@ -338,6 +765,7 @@ impl App {
             }
             Message::ThemeChanged(theme) => {
                 self.theme = theme;
+                self.thumbnails.clear();
                 Task::none()
             }
             Message::CtrlPressed => {
@@ -375,64 +803,90 @@ impl App {
                 }
                 Task::none()
             }
-            Message::WindowResized(width, height) => {
-                self.canvas_size = (width, height);
+            Message::WindowResized(id, width, height) => {
+                if id == self.main_window {
+                    self.canvas_size = (width, height);
+                }
                 Task::none()
             }
-            Message::QuizAnswer(answer) => {
-                self.quiz_answer = Some(answer);
+            Message::QuizAnswer(id, option) => {
+                let picked = self.quiz_answers.entry(id).or_default();
+                if !picked.contains(&option) {
+                    picked.push(option);
+                }
                 Task::none()
             }
-            Message::QuizHttpAnswer(answer) => {
-                self.quiz_http_answer = Some(answer);
+            Message::ExportResults(format) => {
+                // Next to the session file, which holds the same answers
+                let path = match &self.session {
+                    Some(file) => file.path.with_file_name(format.file_name()),
+                    None => PathBuf::from(format.file_name()),
+                };
+                let scorecard = score::Scorecard::new(&self.deck, &self.quiz_answers);
+                Task::perform(scorecard.save(format, path), Message::ResultsExported)
+            }
+            Message::ResultsExported(result) => {
+                self.results_export = Some(result);
                 Task::none()
             }
-            Message::QuizButtonAnswer(answer) => {
-                self.quiz_button_answer = Some(answer);
+            Message::Poll(poll::Event::Started(server)) => {
+                self.poll = Some(server);
+                self.poll_error = None;
                 Task::none()
             }
-            Message::QuizValidationAnswer(answer) => {
-                self.quiz_validation_answer = Some(answer);
+            Message::Poll(poll::Event::Vote(vote)) => {
+                let valid = self.deck.slides.iter().any(|slide| {
+                    matches!(&slide.content, SlideContent::Quiz(quiz)
+                        if quiz.id == vote.quiz && vote.option < quiz.options.len())
+                });
+                if valid {
+                    self.poll_votes
+                        .entry(vote.quiz)
+                        .or_default()
+                        .insert(vote.voter, vote.option);
+                }
+                Task::none()
+            }
+            Message::Poll(poll::Event::Failed(e)) => {
+                eprintln!("warning: polling server stopped: {e}");
+                self.poll = None;
+                self.poll_error = Some(e);
                 Task::none()
             }
             Message::TermEvent(iced_term::Event::BackendCall(_, cmd)) => {
-                self.term.handle(iced_term::Command::ProxyToBackend(cmd));
+                if let Some(term) = &mut self.term {
+                    term.handle(iced_term::Command::ProxyToBackend(cmd));
+                }
                 Task::none()
             }
         }
     }
 
-    fn view(&self) -> Element<'_, Message> {
-        let title = text(self.screen.to_string())
-            .size(self.sz(28))
-            .font(FIRA_MONO)
-            .color(ORANGE);
+    fn view(&self, window: window::Id) -> Element<'_, Message> {
+        if let Some(presenter) = &self.presenter
+            && presenter.window == window
+        {
+            return self.view_presenter(presenter);
+        }
 
-        let content: Element<Message> = match self.screen {
-            Screen::Title => self.view_title_screen(),
-            Screen::Intro => self.view_intro_screen(),
-            Screen::Model => self.view_model_screen(),
-            Screen::View => self.view_view_screen(),
-            Screen::LayoutRowCol => self.view_layout_row_col_screen(),
-            Screen::LayoutContainer => self.view_layout_container_screen(),
-            Screen::LayoutSpacing => self.view_layout_spacing_screen(),
-            Screen::Button => self.view_button_screen(),
-            Screen::TextInput => self.view_text_input_screen(),
-            Screen::Theming => self.view_theming_screen(),
-            Screen::ThemePicker => self.view_theme_picker_screen(),
-            Screen::Message => self.view_message_screen(),
-            Screen::Constructors => self.view_constructors_screen(),
-            Screen::Update => self.view_update_screen(),
-            Screen::Tasks => self.view_tasks_screen(),
-            Screen::Subscriptions => self.view_subscriptions_screen(),
-            Screen::Interactive => self.view_interactive_screen(),
-            Screen::CommunityWidgets => self.view_community_widgets_screen(),
-            Screen::Quiz => self.view_quiz_screen(),
-            Screen::QuizHttp => self.view_quiz_http_screen(),
-            Screen::QuizButton => self.view_quiz_button_screen(),
-            Screen::QuizValidation => self.view_quiz_validation_screen(),
-            Screen::Takeaways => self.view_takeaways_screen(),
-            Screen::Recap => self.view_recap_screen(),
+        let slide = &self.deck.slides[self.current];
+        let title = text(if self.show_overview {
+            "Overview"
+        } else {
+            &slide.title
+        })
+        .size(self.sz(28))
+        .font(FIRA_MONO)
+        .color(ORANGE);
+
+        let content: Element<Message> = if self.show_overview {
+            self.view_overview()
+        } else {
+            match &slide.content {
+                SlideContent::Builtin(screen) => self.view_screen(*screen),
+                SlideContent::Markdown(md) => scrollable(self.md_container(md)).into(),
+                SlideContent::Quiz(quiz) => self.view_quiz(quiz),
+            }
         };
 
         let nav = self.view_navigation();
@@ -471,22 +925,90 @@ impl App {
             nav_bar
         ];
 
-        if self.screen == Screen::Subscriptions {
+        let mut layers = stack![layout];
+
+        if self.screen() == Some(Screen::Subscriptions) && !self.show_overview {
             let chaos_overlay = canvas(chaos::ChaosOverlay {
                 circles: &self.chaos_circles,
             })
             .width(iced::Fill)
             .height(iced::Fill);
+            layers = layers.push(chaos_overlay);
+        }
 
-            container(stack![layout, chaos_overlay])
-                .width(iced::Fill)
-                .height(iced::Fill)
-                .into()
+        if self.show_notes {
+            layers = layers.push(self.view_notes_overlay());
+        }
+
+        container(layers)
+            .width(iced::Fill)
+            .height(iced::Fill)
+            .into()
+    }
+
+    /// The first slide the overview or the presenter window shows without a
+    /// preview yet, starting with the ones nearest to the current slide.
+    fn missing_preview(&self) -> Option<usize> {
+        if let Some(Err(_)) = self.preview_renderer {
+            return None;
+        }
+        let next = self.current + 1;
+        let mut wanted: Vec<usize> = Vec::new();
+        if self.presenter.is_some() && next < self.deck.len() {
+            wanted.push(next);
+        }
+        if self.show_overview {
+            wanted.extend(self.current..self.deck.len());
+            wanted.extend(0..self.current);
+        }
+        wanted
+            .into_iter()
+            .find(|index| !self.thumbnails.contains_key(index))
+    }
+
+    /// Switch to another slide, sliding it in from the direction of travel.
+    fn go_to(&mut self, index: usize) {
+        if index == self.current {
+            return;
+        }
+
+        let entering = if index > self.current {
+            sliding::SlideOffset::entering_forward()
         } else {
-            container(layout)
-                .width(iced::Fill)
-                .height(iced::Fill)
-                .into()
+            sliding::SlideOffset::entering_backward()
+        };
+
+        self.chaos_circles.clear();
+        self.chaos_paused = false;
+        self.current = index;
+        self.slide_offset = Animated::new(entering, Motion::SNAPPY);
+        self.slide_offset
+            .set_target(sliding::SlideOffset::settled());
+    }
+
+    fn view_screen(&self, screen: Screen) -> Element<'_, Message> {
+        match screen {
+            Screen::Title => self.view_title_screen(),
+            Screen::Intro => self.view_intro_screen(),
+            Screen::Model => self.view_model_screen(),
+            Screen::View => self.view_view_screen(),
+            Screen::LayoutRowCol => self.view_layout_row_col_screen(),
+            Screen::LayoutContainer => self.view_layout_container_screen(),
+            Screen::LayoutSpacing => self.view_layout_spacing_screen(),
+            Screen::Button => self.view_button_screen(),
+            Screen::TextInput => self.view_text_input_screen(),
+            Screen::Theming => self.view_theming_screen(),
+            Screen::ThemePicker => self.view_theme_picker_screen(),
+            Screen::Message => self.view_message_screen(),
+            Screen::Constructors => self.view_constructors_screen(),
+            Screen::Update => self.view_update_screen(),
+            Screen::Tasks => self.view_tasks_screen(),
+            Screen::Subscriptions => self.view_subscriptions_screen(),
+            Screen::Interactive => self.view_interactive_screen(),
+            Screen::CommunityWidgets => self.view_community_widgets_screen(),
+            Screen::Takeaways => self.view_takeaways_screen(),
+            Screen::Results => self.view_results_screen(),
+            Screen::Recap => self.view_recap_screen(),
         }
     }
 
@@ -498,22 +1020,20 @@ impl App {
             .spacing(4)
             .align_y(iced::Alignment::Center);
 
-        let prev_btn = if self.screen.is_first() {
+        let prev_btn = if self.is_first() {
             button(prev_label)
         } else {
             button(prev_label).on_press(Message::PrevScreen)
         };
 
-        let next_btn = if self.screen.is_last() {
+        let next_btn = if self.is_last() {
             button(next_label)
         } else {
             button(next_label).on_press(Message::NextScreen)
         };
 
         // Slide indicator
-        let current = self.screen as usize;
-        let total = Screen::COUNT;
-        let slide_indicator = text(format!("{} / {}", current + 1, total))
+        let slide_indicator = text(format!("{} / {}", self.current + 1, self.deck.len()))
             .size(self.sz(14))
             .color(SUBTITLE_COLOR);
 
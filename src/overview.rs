@@ -0,0 +1,100 @@
+use iced::{
+    Border, Element, Event, Length, event, keyboard,
+    widget::{button, column, container, grid, image, scrollable, space, text},
+};
+
+use crate::{App, FIRA_MONO, Message, ORANGE, SUBTITLE_COLOR, TEXT_SIZE};
+
+const COLUMNS: usize = 5;
+
+/// Keys that only matter while the overview is open: typing a slide number.
+pub fn jump_keys(event: Event, status: event::Status, _window: iced::window::Id) -> Option<Message> {
+    use keyboard::Key;
+    use keyboard::key::Named;
+
+    if status == event::Status::Captured {
+        return None;
+    }
+
+    match event {
+        Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) => match key {
+            Key::Character(c) => c.parse().ok().map(Message::JumpDigit),
+            Key::Named(Named::Backspace) => Some(Message::JumpBackspace),
+            Key::Named(Named::Enter) => Some(Message::JumpSubmit),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+impl App {
+    pub fn view_overview(&self) -> Element<'_, Message> {
+        let cards = self.deck.slides.iter().enumerate().map(|(i, slide)| {
+            let preview: Element<'_, Message> = match self.thumbnails.get(&i) {
+                Some(handle) => image(handle.clone())
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .into(),
+                None => space().height(Length::Fill).into(),
+            };
+
+            let is_current = i == self.current;
+            let card = column![
+                text(format!("{}", i + 1))
+                    .size(self.sz(14))
+                    .font(FIRA_MONO)
+                    .color(ORANGE),
+                text(&slide.title).size(self.sz(14)),
+                preview,
+            ]
+            .spacing(self.sp(4.0));
+
+            button(card)
+                .on_press(Message::JumpTo(i))
+                .padding(self.sp(8.0))
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .style(move |theme, status| {
+                    let style = button::secondary(theme, status);
+                    if is_current {
+                        button::Style {
+                            border: Border {
+                                color: ORANGE,
+                                width: 2.0,
+                                radius: 4.0.into(),
+                            },
+                            ..style
+                        }
+                    } else {
+                        style
+                    }
+                })
+                .into()
+        });
+
+        let prompt = if self.jump_input.is_empty() {
+            text("Click a slide or type its number and press Enter")
+                .size(self.sz(TEXT_SIZE - 6))
+                .color(SUBTITLE_COLOR)
+        } else {
+            text(format!("Go to slide {}", self.jump_input))
+                .size(self.sz(TEXT_SIZE - 6))
+                .font(FIRA_MONO)
+        };
+
+        column![
+            prompt,
+            scrollable(
+                container(
+                    grid(cards)
+                        .columns(COLUMNS)
+                        .spacing(self.sp(12.0))
+                        .height(grid::Sizing::AspectRatio(4.0 / 3.0)),
+                )
+                .padding(self.sp(4.0)),
+            ),
+        ]
+        .spacing(self.sp(12.0))
+        .into()
+    }
+}
@@ -0,0 +1,254 @@
+use std::net::{SocketAddr, UdpSocket};
+use std::sync::Arc;
+
+use axum::{
+    Router,
+    extract::{
+        State,
+        ws::{self, WebSocket, WebSocketUpgrade},
+    },
+    response::{Html, Response},
+    routing::get,
+};
+use iced::futures::{SinkExt, Stream};
+use iced::{Subscription, stream};
+use serde::{Deserialize, Serialize};
+use tokio::net::TcpListener;
+use tokio::sync::{mpsc, watch};
+
+/// Page served to attendees; talks to `/ws`.
+const PAGE: &str = include_str!("../assets/poll.html");
+
+/// The quiz attendees can currently vote on, as sent to their phones.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Question {
+    pub quiz: String,
+    pub question: String,
+    pub options: Vec<String>,
+}
+
+/// A vote from an attendee. `voter` is a random id the page keeps in local
+/// storage, so voting again replaces the previous vote.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Vote {
+    pub quiz: String,
+    pub voter: String,
+    pub option: usize,
+}
+
+#[derive(Debug, Clone)]
+pub enum Event {
+    Started(Server),
+    Vote(Vote),
+    Failed(String),
+}
+
+/// Handle to a running polling server.
+#[derive(Debug, Clone)]
+pub struct Server {
+    /// Address attendees should open, e.g. `http://192.168.1.20:8080/`.
+    pub url: String,
+    question: Arc<watch::Sender<Option<Question>>>,
+}
+
+impl Server {
+    /// Show a question on every connected phone, or a waiting screen for `None`.
+    pub fn publish(&self, question: Option<Question>) {
+        self.question.send_if_modified(|current| {
+            let changed = *current != question;
+            *current = question;
+            changed
+        });
+    }
+}
+
+#[derive(Clone)]
+struct Shared {
+    question: watch::Receiver<Option<Question>>,
+    votes: mpsc::Sender<Vote>,
+}
+
+/// Run the polling server on `addr` for as long as the subscription is alive.
+pub fn subscription(addr: SocketAddr) -> Subscription<Event> {
+    Subscription::run_with(addr, |addr| serve(*addr))
+}
+
+fn serve(addr: SocketAddr) -> impl Stream<Item = Event> {
+    stream::channel(100, async move |mut output| {
+        let listener = match TcpListener::bind(addr).await {
+            Ok(listener) => listener,
+            Err(e) => {
+                let _ = output.send(Event::Failed(format!("{addr}: {e}"))).await;
+                return;
+            }
+        };
+        let addr = listener.local_addr().unwrap_or(addr);
+
+        let (question, receiver) = watch::channel(None);
+        let (votes, mut received) = mpsc::channel(100);
+        let server = Server {
+            url: format!("http://{}/", reachable(addr)),
+            question: Arc::new(question),
+        };
+        if output.send(Event::Started(server)).await.is_err() {
+            return;
+        }
+
+        let router = Router::new()
+            .route("/", get(|| async { Html(PAGE) }))
+            .route("/ws", get(upgrade))
+            .with_state(Shared {
+                question: receiver,
+                votes,
+            });
+
+        tokio::select! {
+            result = axum::serve(listener, router).into_future() => {
+                if let Err(e) = result {
+                    let _ = output.send(Event::Failed(e.to_string())).await;
+                }
+            }
+            () = async {
+                while let Some(vote) = received.recv().await {
+                    if output.send(Event::Vote(vote)).await.is_err() {
+                        break;
+                    }
+                }
+            } => {}
+        }
+    })
+}
+
+async fn upgrade(ws: WebSocketUpgrade, State(shared): State<Shared>) -> Response {
+    ws.on_upgrade(move |socket| attendee(socket, shared))
+}
+
+/// Push the current question whenever it changes and forward incoming votes.
+async fn attendee(mut socket: WebSocket, mut shared: Shared) {
+    loop {
+        let question = shared.question.borrow_and_update().clone();
+        let Ok(json) = serde_json::to_string(&question) else {
+            return;
+        };
+        if socket.send(ws::Message::Text(json.into())).await.is_err() {
+            return;
+        }
+
+        loop {
+            tokio::select! {
+                changed = shared.question.changed() => {
+                    if changed.is_err() {
+                        return;
+                    }
+                    break;
+                }
+                incoming = socket.recv() => match incoming {
+                    Some(Ok(ws::Message::Text(text))) => {
+                        // Ignore anything that isn't a well-formed vote
+                        if let Ok(vote) = serde_json::from_str::<Vote>(&text)
+                            && shared.votes.send(vote).await.is_err()
+                        {
+                            return;
+                        }
+                    }
+                    Some(Ok(_)) => {}
+                    Some(Err(_)) | None => return,
+                },
+            }
+        }
+    }
+}
+
+/// Replace a wildcard bind address with this machine's LAN address, so the
+/// URL shown on the slide works from a phone.
+fn reachable(addr: SocketAddr) -> SocketAddr {
+    if !addr.ip().is_unspecified() {
+        return addr;
+    }
+    // Connecting a UDP socket sends nothing but picks the outgoing interface
+    let lan = UdpSocket::bind("0.0.0.0:0")
+        .and_then(|socket| {
+            socket.connect("192.0.2.1:80")?;
+            socket.local_addr()
+        })
+        .map(|local| local.ip());
+    match lan {
+        Ok(ip) => SocketAddr::new(ip, addr.port()),
+        Err(_) => SocketAddr::from(([127, 0, 0, 1], addr.port())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use iced::futures::StreamExt;
+    use tokio_tungstenite::tungstenite;
+
+    use super::*;
+
+    /// Fails the test instead of hanging when something never arrives.
+    async fn within<T>(future: impl Future<Output = T>) -> T {
+        tokio::time::timeout(Duration::from_secs(5), future)
+            .await
+            .expect("timed out")
+    }
+
+    #[tokio::test]
+    async fn publishes_questions_and_forwards_votes() {
+        let mut events = Box::pin(serve(SocketAddr::from(([127, 0, 0, 1], 0))));
+        let server = match within(events.next()).await {
+            Some(Event::Started(server)) => server,
+            other => panic!("expected the server to start, got {other:?}"),
+        };
+
+        // The server only runs while its stream is polled
+        let (forward, mut received) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            while let Some(event) = events.next().await {
+                if forward.send(event).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let url = format!("{}ws", server.url.replacen("http://", "ws://", 1));
+        let (mut socket, _) = within(tokio_tungstenite::connect_async(url))
+            .await
+            .expect("connect to /ws");
+        let mut next_text = async || match within(socket.next()).await {
+            Some(Ok(tungstenite::Message::Text(text))) => text.to_string(),
+            other => panic!("expected a text message, got {other:?}"),
+        };
+
+        // Nothing to vote on yet
+        assert_eq!(next_text().await, "null");
+
+        let question = Question {
+            quiz: "tea".into(),
+            question: "Tea or coffee?".into(),
+            options: vec!["Tea".into(), "Coffee".into()],
+        };
+        server.publish(Some(question.clone()));
+        assert_eq!(
+            next_text().await,
+            serde_json::to_string(&question).expect("serialize question")
+        );
+
+        let send = |text: &str| tungstenite::Message::Text(text.into());
+        within(socket.send(send("not a vote"))).await.expect("send");
+        within(socket.send(send(r#"{"quiz":"tea","voter":"abc","option":1}"#)))
+            .await
+            .expect("send");
+
+        // The malformed message is skipped, so the vote is the next event
+        match within(received.recv()).await {
+            Some(Event::Vote(vote)) => {
+                assert_eq!(vote.quiz, "tea");
+                assert_eq!(vote.voter, "abc");
+                assert_eq!(vote.option, 1);
+            }
+            other => panic!("expected a vote, got {other:?}"),
+        }
+    }
+}
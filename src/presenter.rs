@@ -0,0 +1,167 @@
+use std::time::{Duration, Instant};
+
+use iced::{
+    Color, Element, Length,
+    widget::{button, column, container, image, row, scrollable, space, text},
+    window,
+};
+use lucide_icons::iced::{icon_chevron_left, icon_chevron_right};
+
+use crate::{App, FIRA_MONO, INCORRECT_COLOR, Message, ORANGE, SUBTITLE_COLOR, TEXT_SIZE};
+
+const OVERLAY_BG: Color = Color::from_rgba(0.0, 0.0, 0.0, 0.8);
+
+/// State of the optional second window shown to the speaker.
+pub struct Presenter {
+    pub window: window::Id,
+    pub started: Instant,
+    pub now: Instant,
+}
+
+impl Presenter {
+    pub fn new(window: window::Id) -> Self {
+        let now = Instant::now();
+        Self {
+            window,
+            started: now,
+            now,
+        }
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.now.duration_since(self.started)
+    }
+}
+
+/// Format a duration as `mm:ss`.
+fn clock(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    format!("{:02}:{:02}", secs / 60, secs % 60)
+}
+
+impl App {
+    pub fn view_presenter(&self, presenter: &Presenter) -> Element<'_, Message> {
+        let slide = &self.deck.slides[self.current];
+
+        let notes: Element<'_, Message> = if slide.notes.is_empty() {
+            text("No notes for this slide.")
+                .size(TEXT_SIZE)
+                .color(SUBTITLE_COLOR)
+                .into()
+        } else {
+            scrollable(text(&slide.notes).size(TEXT_SIZE)).into()
+        };
+
+        let current = column![
+            text(format!("{} / {}", self.current + 1, self.deck.len()))
+                .size(14)
+                .color(SUBTITLE_COLOR),
+            text(&slide.title).size(28).font(FIRA_MONO).color(ORANGE),
+            space().height(12),
+            notes,
+        ]
+        .spacing(8)
+        .width(Length::FillPortion(3));
+
+        // Elapsed and remaining time against the deck's planned duration
+        let elapsed = presenter.elapsed();
+        let remaining = match self.deck.duration.checked_sub(elapsed) {
+            Some(left) => text(format!("{} left", clock(left)))
+                .size(18)
+                .color(SUBTITLE_COLOR),
+            None => text(format!("{} over", clock(elapsed - self.deck.duration)))
+                .size(18)
+                .color(INCORRECT_COLOR),
+        };
+        let timer = column![
+            text(clock(elapsed)).size(48).font(FIRA_MONO),
+            row![
+                remaining,
+                button(text("Reset").size(14)).on_press(Message::ResetClock),
+            ]
+            .spacing(12)
+            .align_y(iced::Alignment::Center),
+        ]
+        .spacing(4);
+
+        let next: Element<'_, Message> = match self.deck.slides.get(self.current + 1) {
+            Some(next) => {
+                let thumbnail: Element<'_, Message> = match self.thumbnails.get(&(self.current + 1))
+                {
+                    Some(handle) => image(handle.clone()).width(Length::Fill).into(),
+                    None => container(text("Rendering preview...").size(14))
+                        .width(Length::Fill)
+                        .height(160)
+                        .center_x(Length::Fill)
+                        .center_y(160)
+                        .style(container::rounded_box)
+                        .into(),
+                };
+                column![
+                    text("Next").size(14).color(SUBTITLE_COLOR),
+                    text(&next.title).size(18).font(FIRA_MONO),
+                    thumbnail,
+                ]
+                .spacing(8)
+                .into()
+            }
+            None => text("Last slide").size(18).color(SUBTITLE_COLOR).into(),
+        };
+
+        let prev_btn = button(icon_chevron_left());
+        let next_btn = button(icon_chevron_right());
+        let nav = row![
+            if self.is_first() {
+                prev_btn
+            } else {
+                prev_btn.on_press(Message::PrevScreen)
+            },
+            if self.is_last() {
+                next_btn
+            } else {
+                next_btn.on_press(Message::NextScreen)
+            },
+        ]
+        .spacing(12);
+
+        let sidebar = column![
+            timer,
+            space().height(20),
+            next,
+            space().height(Length::Fill),
+            nav
+        ]
+        .spacing(8)
+        .width(Length::FillPortion(2));
+
+        container(row![current, sidebar].spacing(30))
+            .padding(30)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into()
+    }
+
+    /// Notes for the current slide, drawn over the bottom of the audience window.
+    pub fn view_notes_overlay(&self) -> Element<'_, Message> {
+        let notes = &self.deck.slides[self.current].notes;
+        let body = if notes.is_empty() {
+            "No notes for this slide."
+        } else {
+            notes
+        };
+
+        container(
+            container(text(body).size(self.sz(18)).color(Color::WHITE))
+                .width(iced::Fill)
+                .padding(self.sp(20.0))
+                .style(|_| container::Style {
+                    background: Some(OVERLAY_BG.into()),
+                    ..Default::default()
+                }),
+        )
+        .width(iced::Fill)
+        .height(iced::Fill)
+        .align_bottom(iced::Fill)
+        .into()
+    }
+}
@@ -0,0 +1,108 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::deck::Error;
+
+/// Name of the optional quiz file inside a deck directory.
+pub const FILE: &str = "quizzes.ron";
+
+const BUILTIN: &str = include_str!("../assets/quizzes.ron");
+
+/// A multiple-choice question, e.g.:
+///
+/// ```ron
+/// (
+///     id: "quiz_http",
+///     title: "Quiz: Async Operations",
+///     question: "Where should you make an HTTP request?",
+///     notes: "Relate back to the Tasks slide.",
+///     options: [
+///         (label: "In the View", explanation: "The View can't perform side effects."),
+///         (label: "In a Task from Update", correct: true, explanation: "Correct!"),
+///     ],
+/// )
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub struct Quiz {
+    pub id: String,
+    pub title: String,
+    pub question: String,
+    #[serde(default)]
+    pub notes: String,
+    pub options: Vec<Choice>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Choice {
+    pub label: String,
+    #[serde(default)]
+    pub correct: bool,
+    /// Shown once the option has been picked.
+    pub explanation: String,
+}
+
+impl Quiz {
+    /// The quizzes compiled into the tour.
+    pub fn builtin() -> Vec<Self> {
+        parse(BUILTIN).expect("built-in quizzes are valid")
+    }
+
+    /// Read a quiz file, checking that every quiz can actually be answered and
+    /// that no two quizzes share an id.
+    pub fn load(path: &Path) -> Result<Vec<Self>, Error> {
+        let source = std::fs::read_to_string(path).map_err(|e| Error::Io(path.to_path_buf(), e))?;
+        let quizzes =
+            parse(&source).map_err(|e| Error::Manifest(path.to_path_buf(), Box::new(e)))?;
+
+        let mut ids = BTreeSet::new();
+        for quiz in &quizzes {
+            let reason = if !ids.insert(quiz.id.as_str()) {
+                "is defined more than once"
+            } else if quiz.options.is_empty() {
+                "has no options"
+            } else if quiz.options.len() > 26 {
+                "has more than 26 options"
+            } else if !quiz.options.iter().any(|choice| choice.correct) {
+                "has no correct option"
+            } else {
+                continue;
+            };
+            return Err(Error::InvalidQuiz {
+                id: quiz.id.clone(),
+                reason,
+            });
+        }
+
+        Ok(quizzes)
+    }
+
+    /// Options picked so far, ignoring any that no longer exist, e.g. after
+    /// the quiz file changed under a restored session.
+    pub fn picked(&self, answers: &BTreeMap<String, Vec<usize>>) -> Vec<usize> {
+        answers
+            .get(&self.id)
+            .into_iter()
+            .flatten()
+            .copied()
+            .filter(|&i| i < self.options.len())
+            .collect()
+    }
+
+    pub fn correct_count(&self) -> usize {
+        self.options.iter().filter(|choice| choice.correct).count()
+    }
+
+    /// A quiz is over once a wrong option or every correct one was picked.
+    pub fn is_finished(&self, picked: &[usize]) -> bool {
+        let correct = picked.iter().filter(|&&i| self.options[i].correct).count();
+        correct < picked.len() || correct == self.correct_count()
+    }
+}
+
+fn parse(source: &str) -> Result<Vec<Quiz>, ron::error::SpannedError> {
+    ron::Options::default()
+        .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+        .from_str(source)
+}
@@ -0,0 +1,241 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use serde::Serialize;
+
+use crate::deck::{Deck, SlideContent};
+
+/// How the audience did on every quiz in the deck.
+#[derive(Debug, Clone, Serialize)]
+pub struct Scorecard {
+    /// Quizzes answered correctly on the first attempt.
+    pub score: usize,
+    pub total: usize,
+    pub quizzes: Vec<QuizScore>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct QuizScore {
+    pub id: String,
+    pub question: String,
+    pub outcome: Outcome,
+    /// Labels of the picked options, in the order they were picked.
+    pub picks: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Unanswered,
+    /// Some, but not all, correct options of a multi-answer quiz were found.
+    InProgress,
+    /// Every correct option was found without a wrong pick.
+    FirstTry,
+    /// A wrong option was picked.
+    Missed,
+}
+
+impl Outcome {
+    /// Same spelling as in the JSON export.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Outcome::Unanswered => "unanswered",
+            Outcome::InProgress => "in_progress",
+            Outcome::FirstTry => "first_try",
+            Outcome::Missed => "missed",
+        }
+    }
+}
+
+/// File format for `Scorecard::save`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Csv,
+}
+
+impl Format {
+    /// Default name of the exported file, e.g. `quiz-results.csv`.
+    pub fn file_name(self) -> &'static str {
+        match self {
+            Format::Json => "quiz-results.json",
+            Format::Csv => "quiz-results.csv",
+        }
+    }
+}
+
+impl Scorecard {
+    /// Score every quiz in the deck, in slide order, counting each quiz once.
+    pub fn new(deck: &Deck, answers: &BTreeMap<String, Vec<usize>>) -> Self {
+        let mut quizzes: Vec<QuizScore> = Vec::new();
+
+        for slide in &deck.slides {
+            let SlideContent::Quiz(quiz) = &slide.content else {
+                continue;
+            };
+            if quizzes.iter().any(|score| score.id == quiz.id) {
+                continue;
+            }
+
+            let picked = quiz.picked(answers);
+            let outcome = if picked.is_empty() {
+                Outcome::Unanswered
+            } else if picked.iter().any(|&i| !quiz.options[i].correct) {
+                Outcome::Missed
+            } else if quiz.is_finished(&picked) {
+                Outcome::FirstTry
+            } else {
+                Outcome::InProgress
+            };
+
+            quizzes.push(QuizScore {
+                id: quiz.id.clone(),
+                question: quiz.question.clone(),
+                outcome,
+                picks: picked
+                    .iter()
+                    .map(|&i| quiz.options[i].label.clone())
+                    .collect(),
+            });
+        }
+
+        Self {
+            score: quizzes
+                .iter()
+                .filter(|quiz| quiz.outcome == Outcome::FirstTry)
+                .count(),
+            total: quizzes.len(),
+            quizzes,
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| e.to_string())
+    }
+
+    /// One row per quiz; multiple picks are joined with `; `.
+    pub fn to_csv(&self) -> Result<String, String> {
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        writer
+            .write_record(["id", "question", "outcome", "picks"])
+            .map_err(|e| e.to_string())?;
+        for quiz in &self.quizzes {
+            writer
+                .write_record([
+                    quiz.id.as_str(),
+                    quiz.question.as_str(),
+                    quiz.outcome.as_str(),
+                    &quiz.picks.join("; "),
+                ])
+                .map_err(|e| e.to_string())?;
+        }
+        let bytes = writer.into_inner().map_err(|e| e.to_string())?;
+        String::from_utf8(bytes).map_err(|e| e.to_string())
+    }
+
+    /// Write the scorecard to `path` in the given format.
+    pub async fn save(self, format: Format, path: PathBuf) -> Result<PathBuf, String> {
+        let contents = match format {
+            Format::Json => self.to_json()?,
+            Format::Csv => self.to_csv()?,
+        };
+        tokio::fs::write(&path, contents)
+            .await
+            .map_err(|e| format!("{}: {e}", path.display()))?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The built-in deck with `quiz` answered right, `quiz_http` wrong after
+    /// a right pick, one of two answers to `quiz_button` and `quiz_validation`
+    /// left alone.
+    fn scorecard() -> Scorecard {
+        let answers = BTreeMap::from([
+            ("quiz".to_string(), vec![2]),
+            ("quiz_http".to_string(), vec![2, 0]),
+            ("quiz_button".to_string(), vec![1]),
+            // Options that no longer exist are ignored
+            ("quiz_validation".to_string(), vec![9]),
+        ]);
+        Scorecard::new(&Deck::builtin(), &answers)
+    }
+
+    #[test]
+    fn scores_first_tries_only() {
+        let scorecard = scorecard();
+        let outcomes: Vec<(&str, Outcome)> = scorecard
+            .quizzes
+            .iter()
+            .map(|quiz| (quiz.id.as_str(), quiz.outcome))
+            .collect();
+
+        assert_eq!(
+            outcomes,
+            [
+                ("quiz", Outcome::FirstTry),
+                ("quiz_http", Outcome::Missed),
+                ("quiz_button", Outcome::InProgress),
+                ("quiz_validation", Outcome::Unanswered),
+            ]
+        );
+        assert_eq!((scorecard.score, scorecard.total), (1, 4));
+        assert_eq!(
+            scorecard.quizzes[1].picks,
+            ["In a Task from Update", "In the View"]
+        );
+    }
+
+    #[test]
+    fn csv_has_a_row_per_quiz() {
+        let csv = scorecard().to_csv().unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines.len(), 5);
+        assert_eq!(lines[0], "id,question,outcome,picks");
+        assert!(
+            lines[2].starts_with("quiz_http,")
+                && lines[2].ends_with(",missed,In a Task from Update; In the View"),
+            "{}",
+            lines[2]
+        );
+        assert!(lines[4].ends_with(",unanswered,"), "{}", lines[4]);
+    }
+
+    #[test]
+    fn json_spells_outcomes_like_the_csv() {
+        let json: serde_json::Value =
+            serde_json::from_str(&scorecard().to_json().unwrap()).unwrap();
+
+        assert_eq!(json["score"], 1);
+        assert_eq!(json["total"], 4);
+        for (quiz, outcome) in json["quizzes"].as_array().unwrap().iter().zip([
+            Outcome::FirstTry,
+            Outcome::Missed,
+            Outcome::InProgress,
+            Outcome::Unanswered,
+        ]) {
+            assert_eq!(quiz["outcome"], outcome.as_str());
+        }
+        assert_eq!(json["quizzes"][0]["picks"][0], "In the Update");
+    }
+
+    #[tokio::test]
+    async fn saves_where_asked() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(Format::Csv.file_name());
+
+        let saved = scorecard().save(Format::Csv, path.clone()).await.unwrap();
+        assert_eq!(saved, path);
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            scorecard().to_csv().unwrap()
+        );
+
+        let missing = dir.path().join("missing").join("results.json");
+        assert!(scorecard().save(Format::Json, missing).await.is_err());
+    }
+}
@@ -1,8 +1,11 @@
-use rotalubat::Rotalubat;
-use strum::{Display, EnumCount};
+use strum::{Display, EnumIter};
 
-#[derive(Clone, Copy, PartialEq, Eq, Default, Display, Rotalubat, EnumCount)]
-#[rotalubat(mode = "clamp")]
+use crate::slides::{
+    button, community_widgets, constructors, interactive, intro, layout, message, model, recap,
+    results, subscriptions, tasks, text_input, title, update, view,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Display, EnumIter)]
 pub enum Screen {
     #[default]
     #[strum(serialize = "")]
@@ -65,33 +68,62 @@ pub enum Screen {
     #[strum(serialize = "16. Community Widgets")]
     CommunityWidgets,
 
-    // Quizzes
-    #[strum(serialize = "17. Quiz: Where Does Logic Live?")]
-    Quiz,
-
-    #[strum(serialize = "18. Quiz: Async Operations")]
-    QuizHttp,
-
-    #[strum(serialize = "19. Quiz: Conditional UI")]
-    QuizButton,
-
-    #[strum(serialize = "20. Quiz: Validation Flow")]
-    QuizValidation,
+    // Quizzes (17-20) are data-driven, see `assets/quizzes.ron`
 
     // Recap
     #[strum(serialize = "21. Key Takeaways")]
     Takeaways,
 
-    #[strum(serialize = "22. Recap")]
+    #[strum(serialize = "22. Quiz Results")]
+    Results,
+
+    #[strum(serialize = "23. Recap")]
     Recap,
 }
 
 impl Screen {
-    pub fn is_first(&self) -> bool {
-        *self == Screen::Title
+    /// Stable identifier used to reference the slide from a deck file,
+    /// e.g. `LayoutRowCol` becomes `layout_row_col`.
+    pub fn name(self) -> String {
+        let mut name = String::new();
+        for (i, c) in format!("{self:?}").chars().enumerate() {
+            if c.is_ascii_uppercase() {
+                if i > 0 {
+                    name.push('_');
+                }
+                name.push(c.to_ascii_lowercase());
+            } else {
+                name.push(c);
+            }
+        }
+        name
     }
 
-    pub fn is_last(&self) -> bool {
-        *self == Screen::Recap
+    /// Speaker notes, authored next to each slide's content in `src/slides/`.
+    pub fn notes(self) -> &'static str {
+        let notes = match self {
+            Screen::Title => title::NOTES_TITLE,
+            Screen::Intro => intro::NOTES_INTRO,
+            Screen::Model => model::NOTES_MODEL,
+            Screen::View => view::NOTES_VIEW,
+            Screen::LayoutRowCol => layout::NOTES_ROW_COL,
+            Screen::LayoutContainer => layout::NOTES_CONTAINER,
+            Screen::LayoutSpacing => layout::NOTES_SPACING,
+            Screen::Button => button::NOTES_BUTTON,
+            Screen::TextInput => text_input::NOTES_TEXT_INPUT,
+            Screen::Theming => view::NOTES_THEMING,
+            Screen::ThemePicker => view::NOTES_THEME_PICKER,
+            Screen::Message => message::NOTES_MESSAGE,
+            Screen::Constructors => constructors::NOTES_CONSTRUCTORS,
+            Screen::Update => update::NOTES_UPDATE,
+            Screen::Tasks => tasks::NOTES_TASKS,
+            Screen::Subscriptions => subscriptions::NOTES_SUBSCRIPTIONS,
+            Screen::Interactive => interactive::NOTES_INTERACTIVE,
+            Screen::CommunityWidgets => community_widgets::NOTES_COMMUNITY_WIDGETS,
+            Screen::Takeaways => recap::NOTES_TAKEAWAYS,
+            Screen::Results => results::NOTES_RESULTS,
+            Screen::Recap => recap::NOTES_RECAP,
+        };
+        notes.trim()
     }
 }
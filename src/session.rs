@@ -0,0 +1,103 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use iced::{Color, Theme};
+use serde::{Deserialize, Serialize};
+
+use crate::App;
+
+/// Everything worth keeping when the app restarts mid-talk.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Session {
+    pub slide: usize,
+    pub theme: String,
+    pub hover_color: [f32; 4],
+    pub demo_spacing: f32,
+    pub demo_padding: f32,
+    pub quiz_answers: BTreeMap<String, Vec<usize>>,
+    pub page_poker: page_poker::UrlAnalyzer,
+}
+
+impl Default for Session {
+    fn default() -> Self {
+        Self {
+            slide: 0,
+            theme: Theme::GruvboxLight.to_string(),
+            hover_color: [0.3, 0.7, 1.0, 1.0],
+            demo_spacing: 10.0,
+            demo_padding: 10.0,
+            quiz_answers: BTreeMap::new(),
+            page_poker: page_poker::UrlAnalyzer::default(),
+        }
+    }
+}
+
+/// The session file in use and what was last written to it.
+pub struct SessionFile {
+    pub path: PathBuf,
+    pub saved: Session,
+    /// A save is running; the next one waits for it so they land in order.
+    pub saving: bool,
+}
+
+impl Session {
+    pub fn capture(app: &App) -> Self {
+        let Color { r, g, b, a } = app.hover_color;
+        Self {
+            slide: app.current,
+            theme: app.theme.to_string(),
+            hover_color: [r, g, b, a],
+            demo_spacing: app.demo_spacing,
+            demo_padding: app.demo_padding,
+            quiz_answers: app.quiz_answers.clone(),
+            page_poker: app.page_poker.state().clone(),
+        }
+    }
+
+    pub fn apply(self, app: &mut App) {
+        let [r, g, b, a] = self.hover_color;
+        app.current = self.slide.min(app.deck.len() - 1);
+        if let Some(theme) = Theme::ALL.iter().find(|t| t.to_string() == self.theme) {
+            app.theme = theme.clone();
+        }
+        app.hover_color = Color { r, g, b, a };
+        app.demo_spacing = self.demo_spacing;
+        app.demo_padding = self.demo_padding;
+        app.quiz_answers = self.quiz_answers;
+        app.page_poker.restore(self.page_poker);
+    }
+
+    /// Read a session file; a missing file is a fresh session.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(source) => ron::from_str(&source).map_err(|e| format!("{}: {e}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("{}: {e}", path.display())),
+        }
+    }
+
+    /// Written to a temporary file first and renamed over `path`, so it is
+    /// never left half written.
+    pub async fn save(self, path: PathBuf) -> Result<(), String> {
+        let ron = ron::ser::to_string_pretty(&self, ron::ser::PrettyConfig::default())
+            .map_err(|e| e.to_string())?;
+        let mut temp = path.clone().into_os_string();
+        temp.push(".tmp");
+        tokio::fs::write(&temp, ron)
+            .await
+            .map_err(|e| format!("{}: {e}", path.display()))?;
+        tokio::fs::rename(&temp, &path)
+            .await
+            .map_err(|e| format!("{}: {e}", path.display()))
+    }
+}
+
+/// Delete a session file, treating an already missing one as success.
+pub fn remove(path: &Path) -> Result<(), String> {
+    match std::fs::remove_file(path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(format!("{}: {e}", path.display())),
+    }
+}
@@ -12,6 +12,11 @@ button("Get").on_press(Message::Action)
 ```
 "#;
 
+pub const NOTES_BUTTON: &str = r#"
+on_press takes a Message value, not a closure.
+A button without on_press is disabled, which comes back in the quiz.
+"#;
+
 impl App {
     pub fn view_button_screen(&self) -> Element<'_, Message> {
         let click_text = if self.button_clicks == 0 {
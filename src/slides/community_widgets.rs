@@ -1,11 +1,29 @@
-use iced::{Element, Length, widget::container};
+use iced::{
+    Element, Length,
+    widget::{container, text},
+};
 use iced_term::TerminalView;
 
-use crate::{App, Message};
+use crate::{App, Message, SUBTITLE_COLOR, TEXT_SIZE};
+
+pub const NOTES_COMMUNITY_WIDGETS: &str = r#"
+This is a real terminal from the iced_term crate, running your shell.
+The ecosystem provides many widgets: color pickers, terminals, charts.
+"#;
 
 impl App {
     pub fn view_community_widgets_screen(&self) -> Element<'_, Message> {
-        container(TerminalView::show(&self.term).map(Message::TermEvent))
+        let Some(term) = &self.term else {
+            return container(
+                text("Terminal disabled (--no-terminal)")
+                    .size(self.sz(TEXT_SIZE))
+                    .color(SUBTITLE_COLOR),
+            )
+            .center(Length::Fill)
+            .into();
+        };
+
+        container(TerminalView::show(term).map(Message::TermEvent))
             .width(Length::Fill)
             .height(Length::Fill)
             .padding(self.sp(4.0))
@@ -22,6 +22,11 @@ button(..).on_press(message)  // message: Message (no fn, just a value)
 ```
 "#;
 
+pub const NOTES_CONSTRUCTORS: &str = r#"
+Tuple variants are functions: Message::UrlChanged is fn(String) -> Message.
+That's why we can pass it straight to on_input without writing a closure.
+"#;
+
 impl App {
     pub fn view_constructors_screen(&self) -> Element<'_, Message> {
         scrollable(
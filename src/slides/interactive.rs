@@ -2,6 +2,11 @@ use iced::Element;
 
 use crate::{App, Message};
 
+pub const NOTES_INTERACTIVE: &str = r#"
+Everything we just saw, assembled. Try example.com in each mode.
+Point at the state panel and message log updating as you type.
+"#;
+
 impl App {
     pub fn view_interactive_screen(&self) -> Element<'_, Message> {
         self.page_poker.view().map(Message::PagePoker)
@@ -16,6 +16,11 @@ It separates concerns into four distinct parts:
 4. **View** — transforms state into UI with event handlers
 "#;
 
+pub const NOTES_INTRO: &str = r#"
+Iced follows the Elm Architecture. Name the four parts and point at the circle.
+The key idea: data flows in one direction, Model -> View -> Message -> Update -> Model.
+"#;
+
 impl App {
     pub fn view_intro_screen(&self) -> Element<'_, Message> {
         scrollable(
@@ -36,6 +36,21 @@ column![a, b, c]
 ```
 "#;
 
+pub const NOTES_ROW_COL: &str = r#"
+row! and column! are the building blocks. Everything is nesting.
+Type in the live example to show the input is fully wired up.
+"#;
+
+pub const NOTES_CONTAINER: &str = r#"
+Container holds exactly one child and positions or styles it.
+center_x/center_y plus Fill is the usual way to center something.
+"#;
+
+pub const NOTES_SPACING: &str = r#"
+Drag the sliders: spacing is between children, padding is around them.
+Hold Shift to show the explain() debug outlines.
+"#;
+
 impl App {
     pub fn view_layout_row_col_screen(&self) -> Element<'_, Message> {
         scrollable(
@@ -17,6 +17,11 @@ enum Message {
 ```
 "#;
 
+pub const NOTES_MESSAGE: &str = r#"
+Messages are just data describing what happened, not what to do.
+Result(String) carries the outcome of the async fetch back into update.
+"#;
+
 impl App {
     pub fn view_message_screen(&self) -> Element<'_, Message> {
         scrollable(
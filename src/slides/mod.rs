@@ -8,6 +8,7 @@ pub mod message;
 pub mod model;
 pub mod quiz;
 pub mod recap;
+pub mod results;
 pub mod subscriptions;
 pub mod tasks;
 pub mod text_input;
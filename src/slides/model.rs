@@ -21,6 +21,11 @@ struct UrlAnalyzer {
 ```
 "#;
 
+pub const NOTES_MODEL: &str = r#"
+The Model is plain Rust data. No widgets, no callbacks, nothing Iced-specific.
+This is the Page Poker model we'll build up over the next slides.
+"#;
+
 impl App {
     pub fn view_model_screen(&self) -> Element<'_, Message> {
         scrollable(
@@ -1,12 +1,13 @@
 use iced::{
     Border, Color, Shadow, Theme,
-    widget::{column, container, row, space, text},
+    widget::{column, container, progress_bar, row, space, text},
     widget::button as iced_button,
     Element,
 };
 use iced_anim::widget::button;
 use lucide_icons::iced::{icon_circle_check, icon_circle_x};
 
+use crate::quiz::Quiz;
 use crate::{App, CORRECT_COLOR, FIRA_MONO, INCORRECT_COLOR, Message, ORANGE, SUBTITLE_COLOR};
 
 // WWM dark navy colors
@@ -84,94 +85,118 @@ fn wwm_style(
     }
 }
 
-const LETTERS: [&str; 4] = ["A:", "B:", "C:", "D:"];
-
 impl App {
-    fn view_quiz<'a>(
-        &self,
-        question: &'a str,
-        options: &'a [(&'a str, Message)],
-        answer: Option<u8>,
-        feedbacks: &'a [(u8, &'a str, bool)],
-    ) -> Element<'a, Message> {
-        assert_eq!(options.len(), 4, "WWM quiz requires exactly 4 options");
-
-        // Find which index is correct
-        let correct_idx = feedbacks
-            .iter()
-            .find(|(_, _, c)| *c)
-            .map(|(i, _, _)| *i);
-
-        // Build 4 styled buttons
-        let buttons: Vec<Element<'a, Message>> = options
-            .iter()
-            .enumerate()
-            .map(|(i, (label, msg))| {
-                let idx = i as u8;
-                let is_selected = answer == Some(idx);
-                let is_correct = correct_idx == Some(idx);
-                let answered = answer.is_some();
-
-                let content = row![
-                    text(LETTERS[i]).size(self.sz(18)).font(FIRA_MONO).color(ORANGE),
-                    text(*label).size(self.sz(18)).color(Color::WHITE),
+    pub fn view_quiz<'a>(&'a self, quiz: &'a Quiz) -> Element<'a, Message> {
+        let picked = quiz.picked(&self.quiz_answers);
+        let finished = quiz.is_finished(&picked);
+
+        // Live audience votes, only while the polling server is running
+        let mut votes = vec![0; quiz.options.len()];
+        for &option in self.poll_votes.get(&quiz.id).into_iter().flat_map(|v| v.values()) {
+            votes[option] += 1;
+        }
+        let total_votes: usize = votes.iter().sum();
+
+        let buttons = quiz.options.iter().enumerate().map(|(i, choice)| {
+            let is_selected = picked.contains(&i);
+            let letter = char::from(b'A' + i as u8);
+
+            let content = row![
+                text(format!("{letter}:"))
+                    .size(self.sz(18))
+                    .font(FIRA_MONO)
+                    .color(ORANGE),
+                text(&choice.label).size(self.sz(18)).color(Color::WHITE),
+            ]
+            .spacing(self.sp(10.0))
+            .align_y(iced::Alignment::Center);
+
+            let content: Element<'a, Message> = if self.poll.is_some() {
+                let histogram = row![
+                    progress_bar(0.0..=total_votes.max(1) as f32, votes[i] as f32)
+                        .girth(self.sp(6.0)),
+                    text(votes[i])
+                        .size(self.sz(14))
+                        .font(FIRA_MONO)
+                        .color(Color::WHITE),
                 ]
                 .spacing(self.sp(10.0))
                 .align_y(iced::Alignment::Center);
+                column![content, histogram].spacing(self.sp(8.0)).into()
+            } else {
+                content.into()
+            };
 
-                button(content)
-                    .on_press(msg.clone())
-                    .width(iced::Fill)
-                    .padding([12, 20])
-                    .style(wwm_style(is_selected, is_correct, answered))
+            let button = button(content)
+                .width(iced::Fill)
+                .padding([12, 20])
+                .style(wwm_style(
+                    is_selected,
+                    choice.correct,
+                    finished || is_selected,
+                ));
+            if finished || is_selected {
+                button.into()
+            } else {
+                button
+                    .on_press(Message::QuizAnswer(quiz.id.clone(), i))
                     .into()
-            })
-            .collect();
+            }
+        });
 
-        // 2×2 grid
+        // Two options per row, however many there are
+        let mut buttons: Vec<Element<'a, Message>> = buttons.collect();
+        if buttons.len() % 2 == 1 {
+            buttons.push(space().width(iced::Fill).into());
+        }
+        let mut grid = column![].spacing(self.sp(16.0));
         let mut buttons = buttons.into_iter();
-        let a = buttons.next().unwrap();
-        let b = buttons.next().unwrap();
-        let c = buttons.next().unwrap();
-        let d = buttons.next().unwrap();
-
-        let grid = column![
-            row![a, b].spacing(self.sp(16.0)),
-            row![c, d].spacing(self.sp(16.0)),
-        ]
-        .spacing(self.sp(16.0));
+        while let (Some(left), Some(right)) = (buttons.next(), buttons.next()) {
+            grid = grid.push(row![left, right].spacing(self.sp(16.0)));
+        }
 
-        // Feedback text below
-        let feedback: Element<'_, Message> = match answer {
-            None => text("Select an answer")
+        // Explanations for everything picked so far, in the order picked
+        let feedback: Element<'_, Message> = if picked.is_empty() {
+            text("Select an answer")
                 .size(self.sz(16))
                 .color(SUBTITLE_COLOR)
-                .into(),
-            Some(idx) => {
-                if let Some((_, fb, is_correct)) = feedbacks.iter().find(|(i, _, _)| *i == idx) {
-                    let icon: Element<'_, Message> = if *is_correct {
-                        icon_circle_check().size(self.sz(18)).color(CORRECT_COLOR).into()
-                    } else {
-                        icon_circle_x().size(self.sz(18)).color(INCORRECT_COLOR).into()
-                    };
-                    let color = if *is_correct {
-                        CORRECT_COLOR
-                    } else {
-                        INCORRECT_COLOR
-                    };
-                    row![icon, text(*fb).size(self.sz(16)).color(color)]
-                        .spacing(self.sp(8.0))
-                        .align_y(iced::Alignment::Center)
-                        .into()
+                .into()
+        } else {
+            let mut lines = column![].spacing(self.sp(8.0));
+            for &i in &picked {
+                let choice = &quiz.options[i];
+                let (icon, color) = if choice.correct {
+                    (icon_circle_check(), CORRECT_COLOR)
                 } else {
-                    space().into()
-                }
+                    (icon_circle_x(), INCORRECT_COLOR)
+                };
+                lines = lines.push(
+                    row![
+                        icon.size(self.sz(18)).color(color),
+                        text(&choice.explanation).size(self.sz(16)).color(color),
+                    ]
+                    .spacing(self.sp(8.0))
+                    .align_y(iced::Alignment::Center),
+                );
             }
+            if !finished {
+                let left = quiz.correct_count() - picked.len();
+                lines = lines.push(
+                    text(format!(
+                        "{left} more correct answer{} to find",
+                        if left == 1 { "" } else { "s" }
+                    ))
+                    .size(self.sz(16))
+                    .color(SUBTITLE_COLOR),
+                );
+            }
+            lines.into()
         };
 
         container(
             column![
-                text(question).size(self.sz(28)).color(ORANGE),
+                text(&quiz.question).size(self.sz(28)).color(ORANGE),
+                self.view_poll_status(total_votes),
                 space().height(self.sp(30.0)),
                 grid,
                 space().height(self.sp(20.0)),
@@ -187,79 +212,18 @@ impl App {
         .into()
     }
 
-    pub fn view_quiz_screen(&self) -> Element<'_, Message> {
-        self.view_quiz(
-            "Where should validation of a text input happen?",
-            &[
-                ("In the View", Message::QuizAnswer(0)),
-                ("In the Message", Message::QuizAnswer(1)),
-                ("In the Update", Message::QuizAnswer(2)),
-                ("In the Model", Message::QuizAnswer(3)),
-            ],
-            self.quiz_answer,
-            &[
-                (2, "Correct! The Update function processes input and validates data before updating the Model.", true),
-                (0, "Not quite. The View only renders UI from state — it shouldn't contain logic.", false),
-                (1, "Not quite. Messages are just data describing what happened — they don't contain logic.", false),
-                (3, "Not quite. The Model only holds state, not logic.", false),
-            ],
-        )
-    }
-
-    pub fn view_quiz_http_screen(&self) -> Element<'_, Message> {
-        self.view_quiz(
-            "Where should you make an HTTP request?",
-            &[
-                ("In the View", Message::QuizHttpAnswer(0)),
-                ("In the Message", Message::QuizHttpAnswer(1)),
-                ("In a Task from Update", Message::QuizHttpAnswer(2)),
-                ("In the Model", Message::QuizHttpAnswer(3)),
-            ],
-            self.quiz_http_answer,
-            &[
-                (2, "Correct! HTTP requests are async operations, so they belong in a Task returned from Update.", true),
-                (0, "Not quite. The View only renders UI — it can't perform side effects.", false),
-                (1, "Not quite. Messages are just data — they describe events, not perform actions.", false),
-                (3, "Not quite. The Model only holds state — it doesn't perform operations.", false),
-            ],
-        )
-    }
-
-    pub fn view_quiz_button_screen(&self) -> Element<'_, Message> {
-        self.view_quiz(
-            "How do you disable a button when a field is empty?",
-            &[
-                ("Conditional on_press in View", Message::QuizButtonAnswer(0)),
-                ("Flag in Model, View reads it", Message::QuizButtonAnswer(1)),
-                ("Send a DisableButton message", Message::QuizButtonAnswer(2)),
-                ("Add a disabled bool to Model", Message::QuizButtonAnswer(3)),
-            ],
-            self.quiz_button_answer,
-            &[
-                (0, "Correct! The View can check the condition directly and conditionally call on_press.", true),
-                (1, "Also correct! For complex logic, Update can set a flag that the View reads.", true),
-                (2, "Not quite. Messages describe events, not UI commands.", false),
-                (3, "Not quite. A separate flag is unnecessary — the View can derive disabled state from existing data.", false),
-            ],
-        )
-    }
-
-    pub fn view_quiz_validation_screen(&self) -> Element<'_, Message> {
-        self.view_quiz(
-            "How does input validation with error display work?",
-            &[
-                ("Update → Model → View", Message::QuizValidationAnswer(0)),
-                ("View validates directly", Message::QuizValidationAnswer(1)),
-                ("Update shows error directly", Message::QuizValidationAnswer(2)),
-                ("Message sends ValidateInput", Message::QuizValidationAnswer(3)),
-            ],
-            self.quiz_validation_answer,
-            &[
-                (0, "Correct! Update validates, stores errors in Model, View displays them.", true),
-                (1, "Not quite. The View shouldn't contain validation logic — it only renders.", false),
-                (2, "Not quite. Update validates, but the error must be stored in the Model for View to display.", false),
-                (3, "Not quite. Messages carry data about what happened, not commands for what to do.", false),
-            ],
-        )
+    /// Where attendees can vote, or why they can't.
+    fn view_poll_status(&self, total_votes: usize) -> Element<'_, Message> {
+        let status = match (&self.poll, &self.poll_error) {
+            (Some(server), _) => text(format!(
+                "Vote at {} · {total_votes} vote{}",
+                server.url,
+                if total_votes == 1 { "" } else { "s" }
+            ))
+            .color(SUBTITLE_COLOR),
+            (None, Some(e)) => text(format!("Polling unavailable: {e}")).color(INCORRECT_COLOR),
+            (None, None) => return space().into(),
+        };
+        status.size(self.sz(16)).font(FIRA_MONO).into()
     }
 }
@@ -5,6 +5,14 @@ use iced::{
 
 use crate::{App, ELM_CIRCLE_OF_LIFE, Message, ORANGE, SUBTITLE_COLOR, TEXT_SIZE};
 
+pub const NOTES_TAKEAWAYS: &str = r#"
+Go through each bullet slowly; these are what people should remember.
+"#;
+
+pub const NOTES_RECAP: &str = r#"
+Back to the circle. Thank the audience and open for questions.
+"#;
+
 impl App {
     pub fn view_takeaways_screen(&self) -> Element<'_, Message> {
         let bullet = |s: &str| text(format!("  •  {s}")).size(self.sz(TEXT_SIZE));
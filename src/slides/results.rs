@@ -0,0 +1,108 @@
+use iced::{
+    Element, Length,
+    widget::{column, container, row, scrollable, space, text},
+};
+use iced_anim::widget::button;
+use lucide_icons::iced::{icon_circle_check, icon_circle_dashed, icon_circle_dot, icon_circle_x};
+
+use crate::score::{Format, Outcome, Scorecard};
+use crate::{
+    App, CORRECT_COLOR, FIRA_MONO, INCORRECT_COLOR, Message, ORANGE, SUBTITLE_COLOR, TEXT_SIZE,
+};
+
+pub const NOTES_RESULTS: &str = r#"
+Read out the score, then pick the most missed question and revisit it.
+Export the results before closing the app if the organizer wants them.
+"#;
+
+impl App {
+    pub fn view_results_screen(&self) -> Element<'_, Message> {
+        let scorecard = Scorecard::new(&self.deck, &self.quiz_answers);
+
+        if scorecard.total == 0 {
+            return container(
+                text("This deck has no quizzes.")
+                    .size(self.sz(TEXT_SIZE))
+                    .color(SUBTITLE_COLOR),
+            )
+            .center(iced::Fill)
+            .into();
+        }
+
+        let headline = text(format!(
+            "{} / {} correct on the first try",
+            scorecard.score, scorecard.total
+        ))
+        .size(self.sz(36))
+        .color(ORANGE);
+
+        let rows = scorecard.quizzes.iter().enumerate().map(|(i, quiz)| {
+            let (icon, color, status) = match quiz.outcome {
+                Outcome::FirstTry => (icon_circle_check(), CORRECT_COLOR, "First try"),
+                Outcome::Missed => (icon_circle_x(), INCORRECT_COLOR, "Missed"),
+                Outcome::InProgress => (icon_circle_dot(), SUBTITLE_COLOR, "In progress"),
+                Outcome::Unanswered => (icon_circle_dashed(), SUBTITLE_COLOR, "Not answered"),
+            };
+            let picks = if quiz.picks.is_empty() {
+                String::from("—")
+            } else {
+                quiz.picks.join(", ")
+            };
+
+            row![
+                text(format!("{}.", i + 1))
+                    .size(self.sz(18))
+                    .font(FIRA_MONO)
+                    .color(ORANGE)
+                    .width(self.sp(36.0)),
+                column![
+                    text(quiz.question.clone()).size(self.sz(18)),
+                    text(format!("Picked: {picks}"))
+                        .size(self.sz(14))
+                        .color(SUBTITLE_COLOR),
+                ]
+                .spacing(self.sp(2.0))
+                .width(Length::Fill),
+                row![
+                    icon.size(self.sz(18)).color(color),
+                    text(status).size(self.sz(16)).color(color),
+                ]
+                .spacing(self.sp(6.0))
+                .align_y(iced::Alignment::Center)
+                .width(self.sp(160.0)),
+            ]
+            .spacing(self.sp(12.0))
+            .align_y(iced::Alignment::Center)
+            .into()
+        });
+
+        let status: Element<'_, Message> = match &self.results_export {
+            None => space().into(),
+            Some(Ok(path)) => text(format!("Saved {}", path.display()))
+                .size(self.sz(14))
+                .color(CORRECT_COLOR)
+                .into(),
+            Some(Err(e)) => text(format!("Export failed: {e}"))
+                .size(self.sz(14))
+                .color(INCORRECT_COLOR)
+                .into(),
+        };
+
+        let export = row![
+            button(text("Export JSON")).on_press(Message::ExportResults(Format::Json)),
+            button(text("Export CSV")).on_press(Message::ExportResults(Format::Csv)),
+            status,
+        ]
+        .spacing(self.sp(12.0))
+        .align_y(iced::Alignment::Center);
+
+        column![
+            headline,
+            scrollable(column(rows).spacing(self.sp(14.0))).height(Length::Fill),
+            export,
+        ]
+        .spacing(self.sp(20.0))
+        .padding(self.sp(20.0))
+        .into()
+    }
+}
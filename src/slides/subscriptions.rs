@@ -19,6 +19,11 @@ fn subscription(&self) -> Subscription<Message> {
 ```
 "#;
 
+pub const NOTES_SUBSCRIPTIONS: &str = r#"
+Subscriptions are declarative: subscription() is called after every update.
+Wait for a few circles to spawn from the timer, then hit Panic to clear them.
+"#;
+
 impl App {
     pub fn view_subscriptions_screen(&self) -> Element<'_, Message> {
         scrollable(
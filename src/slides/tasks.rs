@@ -20,6 +20,11 @@ fn update(&mut self, message: Message) -> Task<Message> {
 ```
 "#;
 
+pub const NOTES_TASKS: &str = r#"
+Task::perform runs the future off the UI thread and maps the output to a Message.
+Update stays synchronous and testable; side effects are described, not executed.
+"#;
+
 impl App {
     pub fn view_tasks_screen(&self) -> Element<'_, Message> {
         scrollable(
@@ -13,6 +13,11 @@ text_input("Enter URL (e.g. example.com)", &self.model.url)
 ```
 "#;
 
+pub const NOTES_TEXT_INPUT: &str = r#"
+on_input is called on every keystroke, on_submit on Enter.
+Type a few characters and point at the counters updating.
+"#;
+
 impl App {
     pub fn view_text_input_screen(&self) -> Element<'_, Message> {
         scrollable(
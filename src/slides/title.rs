@@ -6,6 +6,11 @@ use lucide_icons::iced::icon_keyboard;
 
 use crate::{App, Message, ICED_LOGO, ORANGE, SUBTITLE_COLOR};
 
+pub const NOTES_TITLE: &str = r#"
+Welcome everyone. This whole talk is itself an Iced app, so every slide is live.
+Mention that arrow keys navigate and that questions are welcome at any time.
+"#;
+
 impl App {
     pub fn view_title_screen(&self) -> Element<'_, Message> {
         container(
@@ -19,6 +19,11 @@ fn update(&mut self, message: Message) {
 ```
 "#;
 
+pub const NOTES_UPDATE: &str = r#"
+Update is the only place where state changes, hence &mut self.
+Action is still todo!() here; the next slide fills it in with a Task.
+"#;
+
 impl App {
     pub fn view_update_screen(&self) -> Element<'_, Message> {
         scrollable(
@@ -19,6 +19,21 @@ fn view(&self) -> Element<Message> {
 ```
 "#;
 
+pub const NOTES_VIEW: &str = r#"
+View takes &self, so it can't mutate anything. It only describes the UI.
+Widgets declare which Message they produce; they never change state themselves.
+"#;
+
+pub const NOTES_THEMING: &str = r#"
+Every widget has .style(), a closure from theme and status to a Style.
+Pick a color live and hover the button to show the closure captures it.
+"#;
+
+pub const NOTES_THEME_PICKER: &str = r#"
+Built-in themes switch at runtime because the theme is just part of the state.
+Tip: holding Ctrl shows the theme picker in the navigation bar on any slide.
+"#;
+
 impl App {
     pub fn view_theming_screen(&self) -> Element<'_, Message> {
         let hover_color = self.hover_color;